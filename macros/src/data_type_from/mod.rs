@@ -47,7 +47,11 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
                             name: stringify!(#ident),
                             ty: t.#ident.into(),
                             optional: false,
-                            flatten: false
+                            flatten: false,
+                            comments: &[],
+                            doc_tags: &[],
+                            deprecated: None,
+                            constraints: Default::default()
                         }
                     })
                 });
@@ -58,7 +62,9 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
                         generics: vec![],
                         fields: vec![#(#fields),*],
                         tag: None,
-                        type_id: None
+                        type_id: None,
+                        comments: &[],
+                        deprecated: None
                     }.into()
                 }
             }
@@ -72,7 +78,9 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
                     #crate_name::TupleType {
                         name: stringify!(#ident),
                         generics: vec![],
-                        fields: vec![#(#fields),*]
+                        fields: vec![#(#fields),*],
+                        comments: &[],
+                        deprecated: None
                     }.into()
                 }
             }
@@ -0,0 +1,79 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Result;
+
+use crate::utils::Attribute;
+
+/// The validation constraints on a field from `#[specta(min = 0, max_length = 64, pattern = "...")]`
+/// or the `length` and `range` rules of `#[validate(...)]` and `#[garde(...)]`.
+#[derive(Default, Clone)]
+pub struct ConstraintsAttr {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub min_length: Option<u64>,
+    pub max_length: Option<u64>,
+    pub pattern: Option<String>,
+}
+
+impl ConstraintsAttr {
+    /// Parse the `min`, `max`, `min_length`, `max_length`, `pattern`, `length` or `range` key of an attribute.
+    pub fn parse(&mut self, attr: &Attribute) -> Result<()> {
+        if attr.root_ident == "specta" {
+            match attr.key.to_string().as_str() {
+                "min" => self.min = Some(attr.parse_f64()?),
+                "max" => self.max = Some(attr.parse_f64()?),
+                "min_length" => self.min_length = Some(attr.parse_u64()?),
+                "max_length" => self.max_length = Some(attr.parse_u64()?),
+                "pattern" => self.pattern = Some(attr.parse_string()?),
+                _ => {}
+            }
+
+            return Ok(());
+        }
+
+        // The rules of other crates may use constants or expressions which can't be known here so they are ignored
+        let Ok(items) = attr.parse_list() else {
+            return Ok(());
+        };
+        for item in items {
+            match (attr.key.to_string().as_str(), item.key.to_string().as_str()) {
+                ("length", "min") => self.min_length = item.parse_u64().ok().or(self.min_length),
+                ("length", "max") => self.max_length = item.parse_u64().ok().or(self.max_length),
+                ("length", "equal") => {
+                    if let Ok(v) = item.parse_u64() {
+                        self.min_length = Some(v);
+                        self.max_length = Some(v);
+                    }
+                }
+                ("range", "min") => self.min = item.parse_f64().ok().or(self.min),
+                ("range", "max") => self.max = item.parse_f64().ok().or(self.max),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn to_tokens(&self, crate_ref: &TokenStream) -> TokenStream {
+        fn option<T: quote::ToTokens>(v: &Option<T>) -> TokenStream {
+            match v {
+                Some(v) => quote!(Some(#v)),
+                None => quote!(None),
+            }
+        }
+
+        let min = option(&self.min);
+        let max = option(&self.max);
+        let min_length = option(&self.min_length);
+        let max_length = option(&self.max_length);
+        let pattern = option(&self.pattern);
+
+        quote!(#crate_ref::Constraints {
+            min: #min,
+            max: #max,
+            min_length: #min_length,
+            max_length: #max_length,
+            pattern: #pattern,
+        })
+    }
+}
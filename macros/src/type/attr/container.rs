@@ -4,24 +4,35 @@ use syn::Result;
 
 use crate::utils::{Attribute, Inflection};
 
+use super::{DeprecatedAttr, DocTagAttr};
+
 #[derive(Default, Clone)]
 pub struct ContainerAttr {
     pub rename_all: Option<Inflection>,
     pub rename: Option<TokenStream>,
+    pub serde_rename: Option<String>,
     pub tag: Option<String>,
     pub crate_name: Option<String>,
     pub inline: bool,
     pub remote: Option<String>,
     pub export: Option<bool>, // Option is used because if not explicitly set, we enable it
     pub doc: Vec<String>,
-    pub deprecated: Option<String>,
+    pub doc_tags: Vec<DocTagAttr>,
+    pub deprecated: Option<DeprecatedAttr>,
+    pub ffi: bool,
+    pub brand: bool,
+    pub readonly: bool,
 }
 
 impl_parse! {
     ContainerAttr(attr, out) {
         "rename_all" => out.rename_all = out.rename_all.take().or(Some(attr.parse_inflection()?)),
         "rename" => {
+            let root_ident = attr.root_ident.clone();
             let attr = attr.parse_string()?;
+            if root_ident == "serde" {
+                out.serde_rename = out.serde_rename.take().or(Some(attr.clone()));
+            }
             out.rename = out.rename.take().or_else(|| Some({
                 let name = crate::r#type::unraw_raw_ident(&quote::format_ident!("{}", attr));
                 quote::quote!( #name )
@@ -48,12 +59,27 @@ impl_parse! {
                 out.doc.push(attr.parse_string()?);
             }
         },
-        // TODO: Finish implementing by supporting the official `#[deprecated]` attribute: https://github.com/oscartbeaumont/specta/issues/32
-        "deprecated" => {
-            if attr.key == "specta" {
-                out.deprecated = out.deprecated.take().or(Some(attr.parse_string()?));
+        "ffi" => {
+            if attr.root_ident == "specta" {
+                out.ffi = attr.parse_bool().unwrap_or(true);
+            }
+        },
+        "brand" => {
+            if attr.root_ident == "specta" {
+                out.brand = attr.parse_bool().unwrap_or(true);
             }
         },
+        "readonly" => {
+            if attr.root_ident == "specta" {
+                out.readonly = attr.parse_bool().unwrap_or(true);
+            }
+        },
+        "deprecated" | "note" => DeprecatedAttr::parse(&attr, &mut out.deprecated)?,
+        "since" => match attr.root_ident == "deprecated" {
+            true => DeprecatedAttr::parse(&attr, &mut out.deprecated)?,
+            false => out.doc_tags.push(DocTagAttr::parse(&attr)?),
+        },
+        "example" | "doc_tag" => out.doc_tags.push(DocTagAttr::parse(&attr)?),
     }
 }
 
@@ -64,6 +90,7 @@ impl ContainerAttr {
         #[cfg(feature = "serde")]
         Self::try_from_attrs("serde", attrs, &mut result)?;
         Self::try_from_attrs("doc", attrs, &mut result)?;
+        Self::try_from_attrs("deprecated", attrs, &mut result)?;
         Ok(result)
     }
 }
@@ -0,0 +1,51 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Result;
+
+use crate::utils::Attribute;
+
+/// The deprecation of a type, field or variant from Rust's `#[deprecated]` attribute or `#[specta(deprecated = "...")]`.
+#[derive(Default, Clone)]
+pub struct DeprecatedAttr {
+    pub since: Option<String>,
+    pub note: Option<String>,
+}
+
+impl DeprecatedAttr {
+    /// Parse the `deprecated`, `since` or `note` key of an attribute into `out`.
+    pub fn parse(attr: &Attribute, out: &mut Option<Self>) -> Result<()> {
+        match (attr.root_ident.to_string().as_str(), attr.key.to_string().as_str()) {
+            ("deprecated", "deprecated") => {
+                out.get_or_insert_with(Default::default);
+            }
+            ("deprecated", "since") => {
+                out.get_or_insert_with(Default::default).since = Some(attr.parse_string()?);
+            }
+            ("deprecated", "note") | ("specta", "deprecated") => {
+                out.get_or_insert_with(Default::default).note = Some(attr.parse_string()?);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn to_tokens(deprecated: &Option<Self>, crate_ref: &TokenStream) -> TokenStream {
+        let option = |v: &Option<String>| match v {
+            Some(v) => quote!(Some(#v)),
+            None => quote!(None),
+        };
+
+        match deprecated {
+            Some(Self { since, note }) => {
+                let since = option(since);
+                let note = option(note);
+                quote!(Some(#crate_ref::Deprecated {
+                    since: #since,
+                    note: #note,
+                }))
+            }
+            None => quote!(None),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result};
+
+use crate::utils::Attribute;
+
+/// A documentation tag from `#[specta(example = "...")]`, `#[specta(since = "...")]` or `#[specta(doc_tag(name = "...", value = "..."))]`.
+#[derive(Clone)]
+pub struct DocTagAttr {
+    pub name: String,
+    pub value: String,
+}
+
+impl DocTagAttr {
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let key = attr.key.to_string();
+        if key != "doc_tag" {
+            return Ok(Self {
+                name: key,
+                value: attr.parse_string()?,
+            });
+        }
+
+        let (mut name, mut value) = (None, None);
+        for item in attr.parse_list()? {
+            match item.key.to_string().as_str() {
+                "name" => name = Some(item.parse_string()?),
+                "value" => value = Some(item.parse_string()?),
+                key => {
+                    return Err(Error::new(
+                        item.key.span(),
+                        format!("specta: Found unsupported doc_tag attribute '{key}'"),
+                    ))
+                }
+            }
+        }
+
+        match (name, value) {
+            (Some(name), Some(value)) => Ok(Self { name, value }),
+            _ => Err(Error::new(
+                attr.value_span(),
+                "specta: `doc_tag` requires a `name` and a `value`. Eg. `doc_tag(name = \"see\", value = \"...\")`",
+            )),
+        }
+    }
+
+    pub fn to_tokens(tags: &[Self], crate_ref: &TokenStream) -> TokenStream {
+        let tags = tags.iter().map(|Self { name, value }| {
+            quote!(#crate_ref::DocTag {
+                name: #name,
+                value: #value,
+            })
+        });
+
+        quote!(&[#(#tags),*])
+    }
+}
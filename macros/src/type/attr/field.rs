@@ -1,5 +1,6 @@
 use syn::{Result, Type, TypePath};
 
+use super::{ConstraintsAttr, DeprecatedAttr, DocTagAttr};
 use crate::utils::Attribute;
 
 #[derive(Default)]
@@ -10,6 +11,10 @@ pub struct FieldAttr {
     pub skip: bool,
     pub optional: bool,
     pub flatten: bool,
+    pub doc: Vec<String>,
+    pub doc_tags: Vec<DocTagAttr>,
+    pub deprecated: Option<DeprecatedAttr>,
+    pub constraints: ConstraintsAttr,
 }
 
 impl_parse! {
@@ -25,7 +30,21 @@ impl_parse! {
         "skip_deserializing" => out.skip = true,
         "skip_serializing_if" => out.optional = attr.parse_string()? == *"Option::is_none",
         "optional" => out.optional = attr.parse_bool().unwrap_or(true),
-        "flatten" => out.flatten = attr.parse_bool().unwrap_or(true)
+        "flatten" => out.flatten = attr.parse_bool().unwrap_or(true),
+        "doc" => {
+            if attr.key == "doc" {
+                out.doc.push(attr.parse_string()?);
+            }
+        },
+        "deprecated" | "note" => DeprecatedAttr::parse(&attr, &mut out.deprecated)?,
+        "since" => match attr.root_ident == "deprecated" {
+            true => DeprecatedAttr::parse(&attr, &mut out.deprecated)?,
+            false => out.doc_tags.push(DocTagAttr::parse(&attr)?),
+        },
+        "example" | "doc_tag" => out.doc_tags.push(DocTagAttr::parse(&attr)?),
+        "min" | "max" | "min_length" | "max_length" | "pattern" | "length" | "range" => {
+            out.constraints.parse(&attr)?
+        }
     }
 }
 
@@ -35,6 +54,10 @@ impl FieldAttr {
         Self::try_from_attrs("specta", attrs, &mut result)?;
         #[cfg(feature = "serde")]
         Self::try_from_attrs("serde", attrs, &mut result)?;
+        Self::try_from_attrs("doc", attrs, &mut result)?;
+        Self::try_from_attrs("deprecated", attrs, &mut result)?;
+        Self::try_from_attrs("validate", attrs, &mut result)?;
+        Self::try_from_attrs("garde", attrs, &mut result)?;
         Ok(result)
     }
 }
@@ -1,10 +1,16 @@
+pub use constraints::*;
 pub use container::*;
+pub use deprecated::*;
+pub use doc_tag::*;
 pub use field::*;
 pub use r#enum::*;
 pub use r#struct::*;
 pub use variant::*;
 
+mod constraints;
 mod container;
+mod deprecated;
+mod doc_tag;
 mod r#enum;
 mod field;
 mod r#struct;
@@ -1,5 +1,6 @@
 use syn::Result;
 
+use super::DeprecatedAttr;
 use crate::utils::{Attribute, Inflection};
 
 #[derive(Default)]
@@ -7,6 +8,8 @@ pub struct VariantAttr {
     pub rename_all: Option<Inflection>,
     pub rename: Option<String>,
     pub skip: bool,
+    pub doc: Vec<String>,
+    pub deprecated: Option<DeprecatedAttr>,
 }
 
 impl_parse! {
@@ -16,6 +19,12 @@ impl_parse! {
         "skip" => out.skip = attr.parse_bool().unwrap_or(true),
         "skip_serializing" => out.skip = true,
         "skip_deserializing" => out.skip = true,
+        "doc" => {
+            if attr.key == "doc" {
+                out.doc.push(attr.parse_string()?);
+            }
+        },
+        "deprecated" | "since" | "note" => DeprecatedAttr::parse(&attr, &mut out.deprecated)?,
     }
 }
 
@@ -25,6 +34,8 @@ impl VariantAttr {
         Self::try_from_attrs("specta", attrs, &mut result)?;
         #[cfg(feature = "serde")]
         Self::try_from_attrs("serde", attrs, &mut result)?;
+        Self::try_from_attrs("doc", attrs, &mut result)?;
+        Self::try_from_attrs("deprecated", attrs, &mut result)?;
         Ok(result)
     }
 }
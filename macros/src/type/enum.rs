@@ -104,10 +104,12 @@ pub fn parse_enum(
             };
 
             let generic_idents = generic_idents.clone().collect::<Vec<_>>();
+            let comments = &attrs.doc;
+            let deprecated = DeprecatedAttr::to_tokens(&attrs.deprecated, crate_ref);
 
             Ok(match &variant.fields {
                 Fields::Unit => {
-                    quote!(#crate_ref::EnumVariant::Unit(#variant_name_str))
+                    quote!(#crate_ref::EnumVariant::Unit(#variant_name_str, &[#(#comments),*], #deprecated))
                 }
                 Fields::Unnamed(fields) => {
                     let fields = fields
@@ -136,7 +138,9 @@ pub fn parse_enum(
                     quote!(#crate_ref::EnumVariant::Unnamed(#crate_ref::TupleType {
                         name: #variant_name_str,
                         fields: vec![#(#fields),*],
-                        generics: vec![]
+                        generics: vec![],
+                        comments: &[#(#comments),*],
+                        deprecated: #deprecated
                     }))
                 }
                 Fields::Named(fields) => {
@@ -157,6 +161,11 @@ pub fn parse_enum(
                             )?;
 
                             let field_ident_str = unraw_raw_ident(field.ident.as_ref().unwrap());
+                            let field_comments = &field_attrs.doc;
+                            let field_deprecated = DeprecatedAttr::to_tokens(&field_attrs.deprecated, crate_ref);
+                            let field_constraints = field_attrs.constraints.to_tokens(crate_ref);
+                            let field_doc_tags =
+                                DocTagAttr::to_tokens(&field_attrs.doc_tags, crate_ref);
 
                             let field_name = match (field_attrs.rename, attrs.rename_all) {
                                 (Some(name), _) => name,
@@ -168,6 +177,10 @@ pub fn parse_enum(
                                 name: #field_name,
                                 optional: false,
                                 flatten: false,
+                                comments: &[#(#field_comments),*],
+                                doc_tags: #field_doc_tags,
+                                deprecated: #field_deprecated,
+                                constraints: #field_constraints,
                                 ty: {
                                     #generic_vars
 
@@ -182,7 +195,9 @@ pub fn parse_enum(
                         fields: vec![#(#fields),*],
                         generics: vec![],
                         tag: None,
-                        type_id: None
+                        type_id: None,
+                        comments: &[#(#comments),*],
+                        deprecated: #deprecated
                     }))
                 }
             })
@@ -0,0 +1,75 @@
+use syn::{Attribute, Data, Error, Fields, Meta, NestedMeta, Result};
+
+use crate::utils::parse_attrs;
+
+use super::{attr::*, r#struct::decode_field_attrs};
+
+/// The C exporter lays out `#[specta(ffi)]` types using their `DataType` which follows serde.
+/// Reject anything which would make it differ from the memory layout of the Rust type.
+pub fn validate(container_attrs: &ContainerAttr, attrs: &[Attribute], data: &Data) -> Result<()> {
+    let span = proc_macro2::Span::call_site();
+    if !has_stable_repr(attrs)? {
+        return Err(Error::new(
+            span,
+            "specta: `#[specta(ffi)]` types must be `#[repr(C)]` or `#[repr(transparent)]` without a primitive, `packed` or `align` repr as the C exporter doesn't support them",
+        ));
+    } else if container_attrs.rename_all.is_some() {
+        return Err(Error::new(
+            span,
+            "specta: `rename_all` can't be used with `#[specta(ffi)]` as the exported fields must match the Rust fields",
+        ));
+    }
+
+    let fields = match data {
+        Data::Struct(data) => vec![&data.fields],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let attrs = VariantAttr::from_attrs(&mut parse_attrs(&variant.attrs)?)?;
+                match attrs.skip || attrs.rename.is_some() || attrs.rename_all.is_some() {
+                    true => Err(Error::new_spanned(
+                        variant,
+                        "specta: variants of `#[specta(ffi)]` types can't be skipped or renamed as the exported variants must match the Rust variants",
+                    )),
+                    false => Ok(&variant.fields),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Data::Union(_) => vec![],
+    };
+
+    for field in fields.into_iter().flat_map(Fields::iter) {
+        let (field, attrs) = decode_field_attrs(field)?;
+        if attrs.skip || attrs.flatten || attrs.rename.is_some() || attrs.r#type.is_some() {
+            return Err(Error::new_spanned(
+                field,
+                "specta: fields of `#[specta(ffi)]` types can't be skipped, flattened, renamed or have their type overridden as the exported fields must match the Rust fields",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the type has `#[repr(C)]` or `#[repr(transparent)]` and nothing else.
+/// Eg. `#[repr(C, u8)]` is rejected as the C exporter always declares the enum tag as a plain C `enum`.
+fn has_stable_repr(attrs: &[Attribute]) -> Result<bool> {
+    let mut stable = false;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Meta::List(list) = attr.parse_meta()? {
+            for nested in list.nested.iter() {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path))
+                        if path.is_ident("C") || path.is_ident("transparent") =>
+                    {
+                        stable = true
+                    }
+                    _ => return Ok(false),
+                }
+            }
+        }
+    }
+
+    Ok(stable)
+}
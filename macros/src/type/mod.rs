@@ -15,6 +15,7 @@ use self::generics::{
 
 mod attr;
 mod r#enum;
+mod ffi;
 mod generics;
 mod r#struct;
 
@@ -26,14 +27,17 @@ pub fn derive(
         ident,
         generics,
         data,
-        attrs,
+        attrs: raw_attrs,
         ..
     } = &parse_macro_input::parse::<DeriveInput>(input)?;
 
     // We pass all the attributes at the start and when decoding them pop them off the list.
     // This means at the end we can check for any that weren't consumed and throw an error.
-    let mut attrs = parse_attrs(attrs)?;
+    let mut attrs = parse_attrs(raw_attrs)?;
     let container_attrs = ContainerAttr::from_attrs(&mut attrs)?;
+    if container_attrs.ffi {
+        ffi::validate(&container_attrs, raw_attrs, data)?;
+    }
 
     let ident = container_attrs
         .remote
@@ -55,6 +59,7 @@ pub fn derive(
 
     let (inlines, category, can_flatten) = match data {
         Data::Struct(data) => parse_struct(
+            &ident,
             (&container_attrs, StructAttr::from_attrs(&mut attrs)?),
             generics,
             &crate_ref,
@@ -102,7 +107,7 @@ pub fn derive(
 
         let where_bound = add_type_to_where_clause(&quote!(#crate_ref::Type), generics);
 
-        quote!(impl #bounds #crate_ref::Flatten for #ident #type_args #where_bound {})
+        quote!(#[allow(deprecated)] impl #bounds #crate_ref::Flatten for #ident #type_args #where_bound {})
     });
 
     let type_impl_heading = impl_heading(quote!(#crate_ref::Type), &ident, generics);
@@ -119,7 +124,7 @@ pub fn derive(
 
         quote! {
             #[#crate_ref::internal::ctor::ctor]
-            #[allow(non_snake_case)]
+            #[allow(non_snake_case, deprecated)]
             fn #export_fn_name() {
                 let type_map = &mut *#crate_ref::export::TYPES.lock().unwrap();
 
@@ -138,20 +143,26 @@ pub fn derive(
         Some(export) => quote!(Some(#export)),
         None => quote!(None),
     };
-    let deprecated = match container_attrs.deprecated {
-        Some(msg) => quote!(Some(#msg)),
-        None => quote!(None),
-    };
+    let deprecated = DeprecatedAttr::to_tokens(&container_attrs.deprecated, &crate_ref);
+    let doc_tags = DocTagAttr::to_tokens(&container_attrs.doc_tags, &crate_ref);
+    let ffi = container_attrs.ffi;
+    let brand = container_attrs.brand;
+    let readonly = container_attrs.readonly;
 
     Ok(quote! {
         #[automatically_derived]
+        #[allow(deprecated)]
         #type_impl_heading {
             const NAME: &'static str = #name;
             const COMMENTS: &'static [&'static str] = #comments;
+            const DOC_TAGS: &'static [#crate_ref::DocTag] = #doc_tags;
             const SID:#crate_ref::TypeSid = #crate_ref::sid!(@with_specta_path; #crate_name);
             const IMPL_LOCATION: #crate_ref::ImplLocation = #crate_ref::impl_location!(@with_specta_path; #crate_name);
             const EXPORT: Option<bool> = #should_export;
-            const DEPRECATED: Option<&'static str> = #deprecated;
+            const DEPRECATED: Option<#crate_ref::Deprecated> = #deprecated;
+            const FFI: bool = #ffi;
+            const BRAND: bool = #brand;
+            const READONLY: bool = #readonly;
 
             fn inline(opts: #crate_ref::DefOpts, generics: &[#crate_ref::DataType]) -> #crate_ref::DataType {
                 #inlines
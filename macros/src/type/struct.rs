@@ -1,7 +1,7 @@
 use crate::utils::{parse_attrs, unraw_raw_ident};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{DataStruct, Field, Fields, GenericParam, Generics};
+use syn::{DataStruct, Field, Fields, GenericParam, Generics, Ident};
 
 use super::{attr::*, generics::construct_datatype};
 
@@ -25,6 +25,7 @@ pub fn decode_field_attrs(field: &Field) -> syn::Result<(&Field, FieldAttr)> {
 }
 
 pub fn parse_struct(
+    ident: &Ident,
     (container_attrs, struct_attrs): (&ContainerAttr, StructAttr),
     generics: &Generics,
     crate_ref: &TokenStream,
@@ -58,6 +59,8 @@ pub fn parse_struct(
         .iter()
         .map(|(_, ident)| quote!(stringify!(#ident)));
 
+    let comments = &container_attrs.doc;
+
     let definition = match &data.fields {
         Fields::Named(_) => {
             let fields = data.fields.iter().map(decode_field_attrs)
@@ -91,6 +94,10 @@ pub fn parse_struct(
 
                 let optional = field_attrs.optional;
                 let flatten = field_attrs.flatten;
+                let comments = &field_attrs.doc;
+                let doc_tags = DocTagAttr::to_tokens(&field_attrs.doc_tags, crate_ref);
+                let deprecated = DeprecatedAttr::to_tokens(&field_attrs.deprecated, crate_ref);
+                let constraints = field_attrs.constraints.to_tokens(crate_ref);
 
                 let ty = if field_attrs.flatten {
                     quote! {
@@ -128,16 +135,27 @@ pub fn parse_struct(
                     name: #field_name,
                     optional: #optional,
                     flatten: #flatten,
+                    comments: &[#(#comments),*],
+                    doc_tags: #doc_tags,
+                    deprecated: #deprecated,
+                    constraints: #constraints,
                     ty: {
                         #ty
                     }
                 }))
             }).collect::<syn::Result<Vec<TokenStream>>>()?;
 
+            // Serde uses the name of the struct as the value of the tag which isn't affected by `#[specta(rename)]`
             let tag = container_attrs
                 .tag
                 .as_ref()
-                .map(|t| quote!(Some(#t)))
+                .map(|t| {
+                    let value = container_attrs
+                        .serde_rename
+                        .clone()
+                        .unwrap_or_else(|| unraw_raw_ident(ident));
+                    quote!(Some((#t, #value)))
+                })
                 .unwrap_or(quote!(None));
 
             quote!(#crate_ref::ObjectType {
@@ -145,7 +163,9 @@ pub fn parse_struct(
                 generics: vec![#(#definition_generics),*],
                 fields: vec![#(#fields),*],
                 tag: #tag,
-                type_id: Some(std::any::TypeId::of::<Self>())
+                type_id: Some(std::any::TypeId::of::<Self>()),
+                comments: &[#(#comments),*],
+                deprecated: <Self as #crate_ref::Type>::DEPRECATED
             }.into())
         }
         Fields::Unnamed(_) => {
@@ -188,7 +208,9 @@ pub fn parse_struct(
                 quote!(#crate_ref::TupleType {
                     name: <Self as #crate_ref::Type>::NAME,
                     generics: vec![#(#definition_generics),*],
-                    fields: vec![#(#fields),*]
+                    fields: vec![#(#fields),*],
+                    comments: &[#(#comments),*],
+                    deprecated: <Self as #crate_ref::Type>::DEPRECATED
                 }.into())
             }
         }
@@ -197,6 +219,8 @@ pub fn parse_struct(
                 name: <Self as #crate_ref::Type>::NAME,
                 generics: vec![#(#definition_generics),*],
                 fields: vec![],
+                comments: &[#(#comments),*],
+                deprecated: <Self as #crate_ref::Type>::DEPRECATED,
             }.into())
         }
     };
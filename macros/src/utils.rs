@@ -1,4 +1,4 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::format_ident;
 use syn::{
     ext::IdentExt,
@@ -15,6 +15,8 @@ pub enum AttributeValue {
     /// Path value. Eg. `#[specta(type = String)]` or `#[specta(type = ::std::string::String)]`
     /// Path doesn't follow the Rust spec hence the need for this custom parser. We are doing this anyway for backwards compatibility.
     Path(Path),
+    /// Nested attributes. Eg. `#[specta(doc_tag(name = "see", value = "..."))]`
+    List(Span, Vec<Attribute>),
 }
 
 impl AttributeValue {
@@ -22,13 +24,15 @@ impl AttributeValue {
         match self {
             Self::Lit(lit) => lit.span(),
             Self::Path(path) => path.span(),
+            Self::List(span, _) => *span,
         }
     }
 }
 
 impl Parse for AttributeValue {
     fn parse(input: ParseStream) -> Result<Self> {
-        Ok(match input.peek(Lit) {
+        // `syn` parses a leading `-` as part of a number literal. Eg. `#[specta(min = -1)]`
+        Ok(match input.peek(Lit) || input.peek(Token![-]) {
             true => Self::Lit(input.parse()?),
             false => Self::Path(input.parse()?),
         })
@@ -75,6 +79,27 @@ impl Attribute {
         }
     }
 
+    pub fn parse_f64(&self) -> Result<f64> {
+        match &self.value {
+            Some(AttributeValue::Lit(Lit::Int(int))) => int.base10_parse(),
+            Some(AttributeValue::Lit(Lit::Float(float))) => float.base10_parse(),
+            _ => Err(syn::Error::new(
+                self.value_span(),
+                "specta: expected number literal. Eg. `1` or `1.5`",
+            )),
+        }
+    }
+
+    pub fn parse_u64(&self) -> Result<u64> {
+        match &self.value {
+            Some(AttributeValue::Lit(Lit::Int(int))) => int.base10_parse(),
+            _ => Err(syn::Error::new(
+                self.value_span(),
+                "specta: expected integer literal. Eg. `1`",
+            )),
+        }
+    }
+
     pub fn parse_path(&self) -> Result<Path> {
         match &self.value {
             Some(AttributeValue::Path(path)) => Ok(path.clone()),
@@ -85,6 +110,16 @@ impl Attribute {
         }
     }
 
+    pub fn parse_list(&self) -> Result<&[Attribute]> {
+        match &self.value {
+            Some(AttributeValue::List(_, attrs)) => Ok(attrs),
+            _ => Err(syn::Error::new(
+                self.value_span(),
+                "specta: expected list. Eg. `key(name = \"value\")`",
+            )),
+        }
+    }
+
     pub fn parse_inflection(&self) -> Result<Inflection> {
         match &self.value {
             Some(AttributeValue::Lit(Lit::Str(lit))) => {
@@ -120,12 +155,20 @@ impl Parse for Attribute {
         Ok(Self {
             root_ident: Ident::new("TEMP", input.span()),
             key: input.call(Ident::parse_any)?,
-            value: match input.peek(Token![=]) {
-                true => {
-                    input.parse::<Token![=]>()?;
-                    Some(input.parse()?)
-                }
-                false => None,
+            value: if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                Some(input.parse()?)
+            } else if input.peek(syn::token::Paren) {
+                let content;
+                let paren = syn::parenthesized!(content in input);
+                Some(AttributeValue::List(
+                    paren.span,
+                    Punctuated::<Attribute, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .collect(),
+                ))
+            } else {
+                None
             },
         })
     }
@@ -143,7 +186,13 @@ pub fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<Attribute>> {
                 .expect("Attribute path must be an ident")
                 .clone();
 
-            if !(ident == "specta" || ident == "serde" || ident == "doc") {
+            if !(ident == "specta"
+                || ident == "serde"
+                || ident == "doc"
+                || ident == "deprecated"
+                || ident == "validate"
+                || ident == "garde")
+            {
                 return Ok(vec![]);
             }
 
@@ -159,6 +208,61 @@ pub fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<Attribute>> {
                 };
             }
 
+            // `#[deprecated]`, `#[deprecated = "note"]` or `#[deprecated(since = "1.0.0", note = "note")]`
+            if ident == "deprecated" {
+                let mut result = vec![Attribute {
+                    root_ident: ident.clone(),
+                    key: ident.clone(),
+                    value: None,
+                }];
+
+                match attr.parse_meta()? {
+                    syn::Meta::Path(_) => {}
+                    syn::Meta::NameValue(value) => result.push(Attribute {
+                        root_ident: ident.clone(),
+                        key: format_ident!("note"),
+                        value: Some(AttributeValue::Lit(value.lit)),
+                    }),
+                    syn::Meta::List(_) => result.extend(
+                        attr.parse_args_with(Punctuated::<Attribute, Token![,]>::parse_terminated)?
+                            .into_iter()
+                            .map(|a| Attribute {
+                                root_ident: ident.clone(),
+                                ..a
+                            }),
+                    ),
+                }
+
+                return Ok(result);
+            }
+
+            // The attributes of `validator` and `garde` are owned by those crates so any rule we can't parse is skipped instead of causing an error.
+            // Eg. `#[validate(length(min = 1), regex(path = *RE))]`
+            if ident == "validate" || ident == "garde" {
+                let Ok(tokens) = attr.parse_args::<TokenStream>() else {
+                    return Ok(vec![]);
+                };
+
+                let mut rules = vec![TokenStream::new()];
+                for token in tokens {
+                    match &token {
+                        TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                            rules.push(TokenStream::new())
+                        }
+                        _ => rules.last_mut().expect("rules is never empty").extend([token]),
+                    }
+                }
+
+                return Ok(rules
+                    .into_iter()
+                    .filter_map(|rule| syn::parse2::<Attribute>(rule).ok())
+                    .map(|a| Attribute {
+                        root_ident: ident.clone(),
+                        ..a
+                    })
+                    .collect());
+            }
+
             Ok(attr
                 .parse_args_with(Punctuated::<Attribute, Token![,]>::parse_terminated)?
                 .into_iter()
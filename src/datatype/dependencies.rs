@@ -0,0 +1,160 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::*;
+
+impl DataType {
+    /// Get the names of every type referenced by this type using [`DataType::Reference`].
+    pub fn references(&self) -> BTreeSet<&'static str> {
+        let mut refs = BTreeSet::new();
+        collect_references(self, &mut refs);
+        refs
+    }
+}
+
+fn collect_references(typ: &DataType, refs: &mut BTreeSet<&'static str>) {
+    match typ {
+        DataType::List(ty) | DataType::Set(ty) | DataType::Nullable(ty) => {
+            collect_references(ty, refs)
+        }
+        DataType::Record(def) => {
+            collect_references(&def.0, refs);
+            collect_references(&def.1, refs);
+        }
+        DataType::Tuple(TupleType { fields, .. }) => {
+            fields.iter().for_each(|ty| collect_references(ty, refs))
+        }
+        DataType::Object(ObjectType { fields, .. }) => fields
+            .iter()
+            .for_each(|field| collect_references(&field.ty, refs)),
+        DataType::Enum(EnumType { variants, .. }) => {
+            for variant in variants {
+                match variant {
+                    EnumVariant::Unit(..) => {}
+                    EnumVariant::Unnamed(TupleType { fields, .. }) => {
+                        fields.iter().for_each(|ty| collect_references(ty, refs))
+                    }
+                    EnumVariant::Named(ObjectType { fields, .. }) => fields
+                        .iter()
+                        .for_each(|field| collect_references(&field.ty, refs)),
+                }
+            }
+        }
+        DataType::Reference { name, generics, .. } => {
+            refs.insert(name);
+            generics.iter().for_each(|ty| collect_references(ty, refs));
+        }
+        DataType::Any
+        | DataType::Primitive(_)
+        | DataType::Literal(_)
+        | DataType::WellKnown(_)
+        | DataType::Generic(_)
+        | DataType::Placeholder => {}
+    }
+}
+
+/// Order the types so every type comes after the types it references.
+///
+/// Types which reference each other in a cycle can't be ordered so they are returned together as a single group.
+/// Every other group contains a single type. Use [`cycles`] to get only the groups which form a cycle.
+/// References to types which are not in `types` are ignored.
+pub fn dependency_order(types: &TypeDefs) -> Vec<Vec<&'static str>> {
+    let edges = types
+        .iter()
+        .map(|(name, def)| {
+            let mut refs = def.inner.references();
+            refs.retain(|r| types.contains_key(r));
+            (*name, refs)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let mut tarjan = Tarjan {
+        edges: &edges,
+        index: 0,
+        indices: Default::default(),
+        low_links: Default::default(),
+        stack: vec![],
+        groups: vec![],
+    };
+    for name in edges.keys() {
+        if !tarjan.indices.contains_key(name) {
+            tarjan.visit(name);
+        }
+    }
+
+    tarjan.groups
+}
+
+/// Get the groups of types which reference each other in a cycle.
+/// A type which references itself is also considered a cycle.
+pub fn cycles(types: &TypeDefs) -> Vec<Vec<&'static str>> {
+    dependency_order(types)
+        .into_iter()
+        .filter(|group| match &group[..] {
+            [name] => types[name].inner.references().contains(name),
+            _ => true,
+        })
+        .collect()
+}
+
+/// Get the types which are reachable from the roots through [`DataType::Reference`], including the roots themselves.
+/// Roots which are not in `types` are ignored.
+pub fn reachable(types: &TypeDefs, roots: impl IntoIterator<Item = &'static str>) -> TypeDefs {
+    let mut result = TypeDefs::default();
+    let mut queue = roots.into_iter().collect::<Vec<_>>();
+
+    while let Some(name) = queue.pop() {
+        if result.contains_key(name) {
+            continue;
+        }
+
+        if let Some(def) = types.get(name) {
+            queue.extend(def.inner.references());
+            result.insert(name, def.clone());
+        }
+    }
+
+    result
+}
+
+// Tarjan's strongly connected components algorithm. Components are found in reverse topological order which is the order we want as edges point to the referenced types.
+struct Tarjan<'a> {
+    edges: &'a BTreeMap<&'static str, BTreeSet<&'static str>>,
+    index: usize,
+    indices: BTreeMap<&'static str, usize>,
+    low_links: BTreeMap<&'static str, usize>,
+    stack: Vec<&'static str>,
+    groups: Vec<Vec<&'static str>>,
+}
+
+impl Tarjan<'_> {
+    fn visit(&mut self, name: &'static str) {
+        self.indices.insert(name, self.index);
+        self.low_links.insert(name, self.index);
+        self.index += 1;
+        self.stack.push(name);
+
+        let edges = self.edges;
+        for dep in &edges[name] {
+            if !self.indices.contains_key(dep) {
+                self.visit(dep);
+                let low_link = self.low_links[name].min(self.low_links[dep]);
+                self.low_links.insert(name, low_link);
+            } else if self.stack.contains(dep) {
+                let low_link = self.low_links[name].min(self.indices[dep]);
+                self.low_links.insert(name, low_link);
+            }
+        }
+
+        if self.low_links[name] == self.indices[name] {
+            let mut group = vec![];
+            while let Some(member) = self.stack.pop() {
+                group.push(member);
+                if member == name {
+                    break;
+                }
+            }
+            group.sort_unstable();
+            self.groups.push(group);
+        }
+    }
+}
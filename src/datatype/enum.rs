@@ -1,6 +1,6 @@
 use std::any::TypeId;
 
-use crate::datatype::{DataType, ObjectType, TupleType};
+use crate::datatype::{DataType, Deprecated, ObjectType, TupleType};
 
 /// this is used internally to represent the types.
 #[derive(Debug, Clone)]
@@ -23,10 +23,10 @@ impl EnumType {
                 EnumVariant::Named(_) => true,
                 _ => false,
             },
-            EnumRepr::Untagged => matches!(v, EnumVariant::Unit(_) | EnumVariant::Named(_)),
+            EnumRepr::Untagged => matches!(v, EnumVariant::Unit(..) | EnumVariant::Named(_)),
             EnumRepr::Adjacent { .. } => true,
             EnumRepr::Internal { .. } => {
-                matches!(v, EnumVariant::Unit(_) | EnumVariant::Named(_))
+                matches!(v, EnumVariant::Unit(..) | EnumVariant::Named(_))
             }
         });
     }
@@ -57,7 +57,8 @@ pub enum EnumRepr {
 #[derive(Debug, Clone)]
 #[allow(missing_docs)]
 pub enum EnumVariant {
-    Unit(&'static str),
+    /// A unit variant with its name, doc comments and deprecation.
+    Unit(&'static str, &'static [&'static str], Option<Deprecated>),
     Unnamed(TupleType),
     Named(ObjectType),
 }
@@ -66,16 +67,34 @@ impl EnumVariant {
     /// Get the name of the variant.
     pub fn name(&self) -> &'static str {
         match self {
-            Self::Unit(name) => name,
+            Self::Unit(name, ..) => name,
             Self::Unnamed(tuple_type) => tuple_type.name,
             Self::Named(object_type) => object_type.name,
         }
     }
 
+    /// Get the doc comments on the variant.
+    pub fn comments(&self) -> &'static [&'static str] {
+        match self {
+            Self::Unit(_, comments, _) => comments,
+            Self::Unnamed(tuple_type) => tuple_type.comments,
+            Self::Named(object_type) => object_type.comments,
+        }
+    }
+
+    /// Get the deprecation of the variant.
+    pub fn deprecated(&self) -> Option<Deprecated> {
+        match self {
+            Self::Unit(.., deprecated) => *deprecated,
+            Self::Unnamed(tuple_type) => tuple_type.deprecated,
+            Self::Named(object_type) => object_type.deprecated,
+        }
+    }
+
     /// Get the [`DataType`](crate::DataType) of the variant.
     pub fn data_type(&self) -> DataType {
         match self {
-            Self::Unit(_) => unreachable!("Unit enum variants have no type!"),
+            Self::Unit(..) => unreachable!("Unit enum variants have no type!"),
             Self::Unnamed(tuple_type) => tuple_type.clone().into(),
             Self::Named(object_type) => object_type.clone().into(),
         }
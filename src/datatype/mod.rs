@@ -1,8 +1,10 @@
 use std::{any::TypeId, collections::BTreeMap};
 
+mod dependencies;
 mod r#enum;
 mod object;
 
+pub use dependencies::*;
 pub use object::*;
 pub use r#enum::*;
 
@@ -25,13 +27,90 @@ pub struct DefOpts<'a> {
 pub struct DataTypeExt {
     pub name: &'static str,
     pub comments: &'static [&'static str],
+    pub doc_tags: &'static [DocTag],
     pub sid: TypeSid,
     pub impl_location: ImplLocation,
     pub export: Option<bool>,
-    pub deprecated: Option<&'static str>,
+    pub deprecated: Option<Deprecated>,
+    pub ffi: bool,
+    pub brand: bool,
+    pub readonly: bool,
     pub inner: DataType,
 }
 
+/// The deprecation of a type, field or variant from Rust's `#[deprecated]` attribute.
+/// Eg. `#[deprecated(since = "1.0.0", note = "Use `Bar` instead")]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deprecated {
+    /// The version the item was deprecated in.
+    pub since: Option<&'static str>,
+    /// The reason the item was deprecated.
+    pub note: Option<&'static str>,
+}
+
+/// A documentation tag on a type or field from `#[specta(example = "...")]`, `#[specta(since = "...")]` or `#[specta(doc_tag(name = "...", value = "..."))]`.
+/// Exporters with documentation comments render it as `@{name} {value}`. Eg. `@example`, `@since` or `@see`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DocTag {
+    /// The name of the tag without the `@`. Eg. `example`
+    pub name: &'static str,
+    /// The content of the tag. This may span multiple lines.
+    pub value: &'static str,
+}
+
+impl DocTag {
+    /// The name of the tag used for example values.
+    pub const EXAMPLE: &'static str = "example";
+
+    /// Render the tag as the lines of a documentation comment. Eg. `@see https://example.com`
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut lines = self.value.lines();
+        let mut result = vec![match lines.next() {
+            Some(first) => format!("@{} {first}", self.name),
+            None => format!("@{}", self.name),
+        }];
+        result.extend(lines.map(ToString::to_string));
+        result
+    }
+}
+
+/// The validation constraints on a field from `#[specta(min = 0, max_length = 64, pattern = "...")]`
+/// or the `length` and `range` rules of [validator](https://docs.rs/validator) and [garde](https://docs.rs/garde).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Constraints {
+    /// The inclusive minimum of a number.
+    pub min: Option<f64>,
+    /// The inclusive maximum of a number.
+    pub max: Option<f64>,
+    /// The minimum length of a string or number of items in a list.
+    pub min_length: Option<u64>,
+    /// The maximum length of a string or number of items in a list.
+    pub max_length: Option<u64>,
+    /// A regular expression the string must match.
+    pub pattern: Option<&'static str>,
+}
+
+impl Constraints {
+    /// Returns `true` if there are no constraints.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Render the constraints as the lines of a documentation comment using the JSON Schema keywords. Eg. `@minLength 1`
+    pub fn to_lines(&self) -> Vec<String> {
+        [
+            self.min.map(|v| format!("@minimum {v}")),
+            self.max.map(|v| format!("@maximum {v}")),
+            self.min_length.map(|v| format!("@minLength {v}")),
+            self.max_length.map(|v| format!("@maxLength {v}")),
+            self.pattern.map(|v| format!("@pattern {v}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
 /// this is used internally to represent the types.
 #[derive(Debug, Clone, PartialEq)]
 #[allow(missing_docs)]
@@ -40,7 +119,10 @@ pub enum DataType {
     Any,
     Primitive(PrimitiveType),
     Literal(LiteralType),
+    WellKnown(WellKnownType),
     List(Box<DataType>),
+    // A collection of unique values. Eg. `HashSet<T>`
+    Set(Box<DataType>),
     Nullable(Box<DataType>),
     Record(Box<(DataType, DataType)>),
     Tuple(TupleType),
@@ -111,6 +193,36 @@ impl PrimitiveType {
     }
 }
 
+/// A type from a popular crate which has a standard meaning across languages. Eg. `chrono::DateTime` or `uuid::Uuid`.
+/// Exporters can map it to a native type of the language. Otherwise it's exported as its serialized representation from [`repr`](Self::repr).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WellKnownType {
+    /// A date and time with a UTC offset serialized as an RFC 3339 string. Eg. `chrono::DateTime` or `time::OffsetDateTime`
+    DateTime,
+    /// A date and time without a timezone. Eg. `chrono::NaiveDateTime` or `time::PrimitiveDateTime`
+    NaiveDateTime,
+    /// A calendar date. Eg. `chrono::NaiveDate` or `time::Date`
+    Date,
+    /// A time of day. Eg. `chrono::NaiveTime` or `time::Time`
+    Time,
+    /// A UUID serialized as a hyphenated string. Eg. `uuid::Uuid`
+    Uuid,
+    /// An arbitrary precision decimal number serialized as a string. Eg. `rust_decimal::Decimal` or `bigdecimal::BigDecimal`
+    Decimal,
+    /// Any JSON value. Eg. `serde_json::Value`
+    Json,
+}
+
+impl WellKnownType {
+    /// The type the value is serialized as.
+    pub fn repr(&self) -> DataType {
+        match self {
+            Self::Json => DataType::Any,
+            _ => DataType::Primitive(PrimitiveType::String),
+        }
+    }
+}
+
 /// this is used internally to represent the types.
 #[derive(Debug, Clone, PartialEq)]
 #[allow(missing_docs)]
@@ -118,6 +230,10 @@ pub struct TupleType {
     pub name: &'static str,
     pub fields: Vec<DataType>,
     pub generics: Vec<&'static str>,
+    /// The doc comments on the struct or enum variant.
+    pub comments: &'static [&'static str],
+    /// The deprecation of the enum variant.
+    pub deprecated: Option<Deprecated>,
 }
 
 /// this is used internally to represent the types.
@@ -185,6 +301,8 @@ impl<T: Into<DataType> + 'static> From<Vec<T>> for DataType {
                         name: "",
                         fields: vec![t.into()],
                         generics: vec![],
+                        comments: &[],
+                        deprecated: None,
                     })
                 })
                 .collect(),
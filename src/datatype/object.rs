@@ -1,6 +1,6 @@
 use std::any::TypeId;
 
-use crate::DataType;
+use crate::{Constraints, DataType, Deprecated, DocTag};
 
 /// this is used internally to represent the types.
 #[derive(Debug, Clone)]
@@ -10,6 +10,14 @@ pub struct ObjectField {
     pub ty: DataType,
     pub optional: bool,
     pub flatten: bool,
+    /// The doc comments on the field.
+    pub comments: &'static [&'static str],
+    /// The documentation tags on the field. Eg. `@example`
+    pub doc_tags: &'static [DocTag],
+    /// The deprecation of the field.
+    pub deprecated: Option<Deprecated>,
+    /// The validation constraints on the field.
+    pub constraints: Constraints,
 }
 
 /// this is used internally to represent the types.
@@ -19,8 +27,13 @@ pub struct ObjectType {
     pub name: &'static str,
     pub generics: Vec<&'static str>,
     pub fields: Vec<ObjectField>,
-    pub tag: Option<&'static str>,
+    /// The field added by `#[serde(tag = "...")]` and its value, which is the name serde gives the struct.
+    pub tag: Option<(&'static str, &'static str)>,
     pub type_id: Option<TypeId>,
+    /// The doc comments on the struct or enum variant.
+    pub comments: &'static [&'static str],
+    /// The deprecation of the enum variant.
+    pub deprecated: Option<Deprecated>,
 }
 
 impl PartialEq for ObjectType {
@@ -1,22 +1,82 @@
+use crate::formatter::FormatterFn;
+#[cfg(feature = "export")]
 use crate::ts::{ExportConfiguration, TsExportError};
 use crate::*;
+#[cfg(feature = "export")]
 use once_cell::sync::Lazy;
+#[cfg(feature = "export")]
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "export")]
 use std::sync::Mutex;
+use thiserror::Error;
 
 /// Global type store for collecting custom types to export.
 ///
 /// Populated by `#[ctor]` functions defined in the [`Type`](derive@crate::Type) macro.
+#[cfg(feature = "export")]
 pub static TYPES: Lazy<Mutex<BTreeMap<&'static str, DataTypeExt>>> = Lazy::new(Default::default);
 
 /// Exports all types in the [`TYPES`](static@crate::export::TYPES) map to the provided TypeScript file.
-pub fn ts(path: &str) -> Result<(), TsExportError> {
-    ts_with_cfg(&ExportConfiguration::default(), path)
+#[cfg(feature = "export")]
+pub fn ts(output: impl Into<Output>) -> Result<(), TsExportError> {
+    ts_with_cfg(&ExportConfiguration::default(), output)
 }
 
 /// Exports all types in the [`TYPES`](static@crate::export::TYPES) map to the provided TypeScript file but allow you to provide a configuration for the exporter.
-pub fn ts_with_cfg(cfg: &ExportConfiguration, path: &str) -> Result<(), TsExportError> {
-    let mut out = "// This file has been generated by Specta. DO NOT EDIT.\n\n".to_string();
+#[cfg(feature = "export")]
+pub fn ts_with_cfg(
+    cfg: &ExportConfiguration,
+    output: impl Into<Output>,
+) -> Result<(), TsExportError> {
+    let output = output.into();
+    write_if_changed(&output.path, &render_ts(cfg, &output)?)?;
+    Ok(())
+}
+
+/// Check that the provided TypeScript file already contains the types in the [`TYPES`](static@crate::export::TYPES) map without writing anything.
+/// This is intended to be run in CI to detect bindings exported with [`ts`] which haven't been regenerated.
+#[cfg(feature = "export")]
+pub fn ts_check(output: impl Into<Output>) -> Result<(), ExportError> {
+    ts_check_with_cfg(&ExportConfiguration::default(), output)
+}
+
+/// Check that the provided TypeScript file already contains the types in the [`TYPES`](static@crate::export::TYPES) map but allow you to provide a configuration for the exporter.
+/// Returns [`ExportError::Outdated`] if the file is missing or differs from what [`ts_with_cfg`] would write.
+#[cfg(feature = "export")]
+pub fn ts_check_with_cfg(
+    cfg: &ExportConfiguration,
+    output: impl Into<Output>,
+) -> Result<(), ExportError> {
+    let output = output.into();
+    let out = render_ts(cfg, &output).map_err(|err| match err {
+        TsExportError::Format(err) => ExportError::Format {
+            path: output.path.clone(),
+            err,
+        },
+        err => ExportError::Language {
+            path: output.path.clone(),
+            err: err.into(),
+        },
+    })?;
+
+    check_file(output.path, &out)
+}
+
+#[cfg(feature = "export")]
+fn render_ts(cfg: &ExportConfiguration, output: &Output) -> Result<String, TsExportError> {
+    let mut out = format!("{}\n\n", output.header.as_deref().unwrap_or(ts::HEADER));
+
+    if cfg.json_value {
+        out += ts::JSON_VALUE_DECLARATION;
+        out += "\n\n";
+    }
+
+    for declaration in &output.declarations {
+        out += declaration;
+        out += "\n\n";
+    }
 
     for typ in (*TYPES.lock().expect("Failed to acquire lock on 'TYPES'")).values() {
         if typ.export.unwrap_or(cfg.export_by_default.unwrap_or(true)) {
@@ -25,5 +85,426 @@ pub fn ts_with_cfg(cfg: &ExportConfiguration, path: &str) -> Result<(), TsExport
         }
     }
 
-    std::fs::write(path, out).map_err(Into::into)
+    if let Some(footer) = &output.footer {
+        out += footer;
+        out += "\n";
+    }
+
+    if let Some(formatter) = output.formatter.or(cfg.formatter) {
+        out = formatter(&output.path, &out).map_err(TsExportError::Format)?;
+    }
+
+    Ok(out)
+}
+
+/// A file which exported types are written to, along with any content surrounding the types.
+///
+/// A path can be used anywhere an `Output` is expected.
+///
+/// ```rust
+/// use specta::export::Output;
+///
+/// let output = Output::new("./bindings.ts")
+///     .header("/* eslint-disable */")
+///     .declaration("export type Json = string | number | boolean | null | Json[] | { [key: string]: Json }");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Output {
+    path: PathBuf,
+    header: Option<String>,
+    declarations: Vec<String>,
+    footer: Option<String>,
+    formatter: Option<FormatterFn>,
+}
+
+impl Output {
+    /// Construct a new `Output` which writes to the provided path
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            header: None,
+            declarations: vec![],
+            footer: None,
+            formatter: None,
+        }
+    }
+
+    /// Configure the text at the start of the file. Eg. a license banner or `// @ts-nocheck`
+    /// This replaces the default header of the language ([`Language::header`](crate::Language::header)). Use an empty string to omit it.
+    pub fn header(mut self, header: impl Into<String>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Add a hand-written declaration which is inserted after the header and before the exported types.
+    pub fn declaration(mut self, declaration: impl Into<String>) -> Self {
+        self.declarations.push(declaration.into());
+        self
+    }
+
+    /// Configure the text at the end of the file.
+    pub fn footer(mut self, footer: impl Into<String>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Configure a function which is responsible for formatting the file before it's written. Eg. [`formatter::prettier`](crate::formatter::prettier)
+    pub fn formatter(mut self, formatter: FormatterFn) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// The path the file is written to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn format(&self, contents: String) -> std::io::Result<String> {
+        match self.formatter {
+            Some(formatter) => formatter(&self.path, &contents),
+            None => Ok(contents),
+        }
+    }
+
+    fn render(&self, body: &str) -> String {
+        self.header
+            .iter()
+            .chain(&self.declarations)
+            .map(String::as_str)
+            .chain([body])
+            .chain(self.footer.as_deref())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl From<&str> for Output {
+    fn from(path: &str) -> Self {
+        Self::new(path)
+    }
+}
+
+impl From<String> for Output {
+    fn from(path: String) -> Self {
+        Self::new(path)
+    }
+}
+
+impl From<&Path> for Output {
+    fn from(path: &Path) -> Self {
+        Self::new(path)
+    }
+}
+
+impl From<PathBuf> for Output {
+    fn from(path: PathBuf) -> Self {
+        Self::new(path)
+    }
+}
+
+/// Write the contents to the file unless it already contains them.
+/// This avoids touching the file's modification time so file watchers (Eg. frontend dev servers) aren't triggered when nothing changed.
+///
+/// Returns `true` if the file was written.
+pub fn write_if_changed(path: impl AsRef<Path>, contents: &str) -> std::io::Result<bool> {
+    let path = path.as_ref();
+    match std::fs::read(path) {
+        Ok(existing) if existing == contents.as_bytes() => Ok(false),
+        Ok(_) => std::fs::write(path, contents).map(|_| true),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            std::fs::write(path, contents).map(|_| true)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns [`ExportError::Outdated`] if the file doesn't contain exactly the contents.
+fn check_file(path: PathBuf, contents: &str) -> Result<(), ExportError> {
+    let existing = match std::fs::read_to_string(&path) {
+        Ok(existing) => existing,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(ExportError::Io { path, err }),
+    };
+
+    match existing == contents {
+        true => Ok(()),
+        false => Err(ExportError::Outdated {
+            diff: DiffSummary::new(&existing, contents),
+            path,
+        }),
+    }
+}
+
+type ExportFn =
+    Box<dyn Fn(&[&DataTypeExt]) -> Result<String, Box<dyn std::error::Error + Send + Sync>>>;
+
+/// Exports types to multiple languages in a single run.
+///
+/// The types are taken from the [`TYPES`](static@crate::export::TYPES) map populated by the `export` feature
+/// unless a [`TypeCollection`](crate::TypeCollection) is provided using [`types`](Self::types).
+///
+/// ```rust,no_run
+/// use specta::{export::Export, ts::{ExportConfiguration, Typescript}};
+///
+/// Export::new()
+///     .language::<Typescript>(ExportConfiguration::default(), "./bindings.ts")
+///     .write()
+///     .unwrap();
+/// ```
+pub struct Export {
+    languages: Vec<(ExportFn, Output)>,
+    export_by_default: bool,
+    dependency_order: bool,
+    roots: Option<BTreeSet<&'static str>>,
+    types: Option<TypeCollection>,
+}
+
+impl Export {
+    /// Construct a new `Export` without any languages
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a [`Language`](crate::Language) which will be written to the provided [`Output`].
+    /// The file starts with the [default header](crate::Language::header) of the language unless [`Output::header`] is configured.
+    pub fn language<L>(mut self, conf: L::Config, output: impl Into<Output>) -> Self
+    where
+        L: Language,
+        L::Config: 'static,
+        L::Error: Send + Sync + 'static,
+    {
+        let mut output = output.into();
+        if output.header.is_none() {
+            output.header = L::header(&conf);
+        }
+
+        self.languages.push((
+            Box::new(move |types| L::export_many(&conf, types.iter().copied()).map_err(Into::into)),
+            output,
+        ));
+        self
+    }
+
+    /// Configure whether or not to export types by default.
+    /// This can be overridden on a type basis by using `#[specta(export)]`
+    pub fn export_by_default(mut self, x: bool) -> Self {
+        self.export_by_default = x;
+        self
+    }
+
+    /// Configure whether types are exported after the types they reference instead of in alphabetical order.
+    /// Types which reference each other in a cycle are exported next to each other. Use [`cycles`](crate::cycles) to find them.
+    pub fn dependency_order(mut self, x: bool) -> Self {
+        self.dependency_order = x;
+        self
+    }
+
+    /// Export the types in the collection instead of the global [`TYPES`](static@crate::export::TYPES) map.
+    /// Every type in the collection is exported regardless of `#[specta(export = false)]`.
+    pub fn types(mut self, types: TypeCollection) -> Self {
+        self.types = Some(types);
+        self
+    }
+
+    /// Only export `T` and the types it references.
+    /// Once a root has been added only the types reachable from the roots are exported, regardless of `#[specta(export = false)]`.
+    pub fn root<T: Type>(self) -> Self {
+        self.roots([T::NAME])
+    }
+
+    /// Only export the types referenced by the [`DataType`]. Eg. the arguments of a function.
+    /// See [`root`](Self::root) for how roots affect which types are exported.
+    pub fn root_datatype(self, typ: &DataType) -> Self {
+        self.roots(typ.references())
+    }
+
+    /// Only export the types used by the arguments and result of the function.
+    /// See [`root`](Self::root) for how roots affect which types are exported.
+    #[cfg(feature = "functions")]
+    pub fn root_function(self, function: &crate::functions::FunctionDataType) -> Self {
+        function
+            .args
+            .iter()
+            .map(|(_, ty)| ty)
+            .chain([&function.result])
+            .fold(self, |this, ty| this.root_datatype(ty))
+    }
+
+    fn roots(mut self, roots: impl IntoIterator<Item = &'static str>) -> Self {
+        self.roots
+            .get_or_insert_with(Default::default)
+            .extend(roots);
+        self
+    }
+
+    /// Export the types to every configured language.
+    /// The [`TYPES`](static@crate::export::TYPES) map is only locked once so every language receives the same types.
+    /// Files which already contain the exported types are left untouched.
+    pub fn write(&self) -> Result<(), ExportError> {
+        for (path, out) in self.render()? {
+            write_if_changed(&path, &out).map_err(|err| ExportError::Io { path, err })?;
+        }
+
+        Ok(())
+    }
+
+    /// Check that every file already contains the exported types without writing anything.
+    /// This is intended to be run in CI to detect bindings which haven't been regenerated.
+    ///
+    /// Returns [`ExportError::Outdated`] for the first file which is missing or differs.
+    pub fn check(&self) -> Result<(), ExportError> {
+        for (path, out) in self.render()? {
+            check_file(path, &out)?;
+        }
+
+        Ok(())
+    }
+
+    // `#[specta(export)]` only applies to the global type store as types in a `TypeCollection` were registered explicitly.
+    fn select(&self, all: &TypeDefs, filter_export: bool) -> TypeDefs {
+        match &self.roots {
+            Some(roots) => reachable(all, roots.iter().copied()),
+            None => all
+                .iter()
+                .filter(|(_, typ)| !filter_export || typ.export.unwrap_or(self.export_by_default))
+                .map(|(name, typ)| (*name, typ.clone()))
+                .collect(),
+        }
+    }
+
+    fn render(&self) -> Result<Vec<(PathBuf, String)>, ExportError> {
+        let types = match &self.types {
+            Some(types) => self.select(types.as_type_defs(), false),
+            #[cfg(feature = "export")]
+            None => self.select(
+                &TYPES.lock().expect("Failed to acquire lock on 'TYPES'"),
+                true,
+            ),
+            #[cfg(not(feature = "export"))]
+            None => TypeDefs::default(),
+        };
+
+        let ordered = match self.dependency_order {
+            true => dependency_order(&types)
+                .into_iter()
+                .flatten()
+                .map(|name| &types[name])
+                .collect::<Vec<_>>(),
+            false => types.values().collect(),
+        };
+
+        self.languages
+            .iter()
+            .map(|(export, output)| {
+                let out = export(&ordered).map_err(|err| ExportError::Language {
+                    path: output.path.clone(),
+                    err,
+                })?;
+
+                output
+                    .format(output.render(&out))
+                    .map(|out| (output.path.clone(), out))
+                    .map_err(|err| ExportError::Format {
+                        path: output.path.clone(),
+                        err,
+                    })
+            })
+            .collect()
+    }
+}
+
+impl Default for Export {
+    fn default() -> Self {
+        Self {
+            languages: vec![],
+            export_by_default: true,
+            dependency_order: false,
+            roots: None,
+            types: None,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum ExportError {
+    #[error("Failed to export types to '{}': {err}", .path.display())]
+    Language {
+        path: PathBuf,
+        err: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("Failed to format '{}': {err}", .path.display())]
+    Format { path: PathBuf, err: std::io::Error },
+    #[error("Failed to write '{}': {err}", .path.display())]
+    Io { path: PathBuf, err: std::io::Error },
+    #[error("'{}' is out of date ({diff}). Regenerate the bindings to fix this.", .path.display())]
+    Outdated { path: PathBuf, diff: DiffSummary },
+}
+
+/// A summary of the lines which differ between a file on disk and the freshly exported types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffSummary {
+    /// Lines which are exported but not in the file.
+    pub added: Vec<String>,
+    /// Lines which are in the file but are no longer exported.
+    pub removed: Vec<String>,
+}
+
+impl DiffSummary {
+    /// Lines which aren't part of the longest common subsequence of both files have been added or removed so moved lines are reported too.
+    fn new(existing: &str, expected: &str) -> Self {
+        let old = existing.lines().collect::<Vec<_>>();
+        let new = expected.lines().collect::<Vec<_>>();
+
+        // `lcs[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`
+        let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+        for i in (0..old.len()).rev() {
+            for j in (0..new.len()).rev() {
+                lcs[i][j] = match old[i] == new[j] {
+                    true => lcs[i + 1][j + 1] + 1,
+                    false => lcs[i + 1][j].max(lcs[i][j + 1]),
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        let (mut added, mut removed) = (vec![], vec![]);
+        while i < old.len() && j < new.len() {
+            if old[i] == new[j] {
+                i += 1;
+                j += 1;
+            } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+                removed.push(old[i].to_string());
+                i += 1;
+            } else {
+                added.push(new[j].to_string());
+                j += 1;
+            }
+        }
+        removed.extend(old[i..].iter().map(ToString::to_string));
+        added.extend(new[j..].iter().map(ToString::to_string));
+
+        Self { added, removed }
+    }
+}
+
+impl std::fmt::Display for DiffSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} line(s) added, {} line(s) removed",
+            self.added.len(),
+            self.removed.len()
+        )?;
+
+        for line in &self.added {
+            write!(f, "\n+ {line}")?;
+        }
+        for line in &self.removed {
+            write!(f, "\n- {line}")?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,96 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+    process::{Command, Stdio},
+    thread,
+};
+
+/// The signature for a function responsible for formatting the contents of an exported file.
+///
+/// It receives the path the file will be written to (so the formatter can detect the language) and the unformatted contents.
+/// The file is only written if the formatted contents differ from what is already on disk.
+pub type FormatterFn = fn(&Path, &str) -> io::Result<String>;
+
+/// Format the file using [Prettier](https://prettier.io). The parser is inferred from the file extension.
+pub fn prettier(path: &Path, contents: &str) -> io::Result<String> {
+    run(
+        Command::new("prettier").arg("--stdin-filepath").arg(path),
+        contents,
+    )
+}
+
+/// Format the file using [dprint](https://dprint.dev). The plugin is inferred from the file extension.
+pub fn dprint(path: &Path, contents: &str) -> io::Result<String> {
+    run(
+        Command::new("dprint").arg("fmt").arg("--stdin").arg(path),
+        contents,
+    )
+}
+
+/// Format the file using [SwiftFormat](https://github.com/nicklockwood/SwiftFormat).
+pub fn swiftformat(path: &Path, contents: &str) -> io::Result<String> {
+    run(
+        Command::new("swiftformat")
+            .arg("--quiet")
+            .arg("--stdinpath")
+            .arg(path),
+        contents,
+    )
+}
+
+/// Format the file using [rustfmt](https://github.com/rust-lang/rustfmt).
+pub fn rustfmt(_path: &Path, contents: &str) -> io::Result<String> {
+    run(
+        Command::new("rustfmt").arg("--edition").arg("2021"),
+        contents,
+    )
+}
+
+/// Format the file using [elm-format](https://github.com/avh4/elm-format).
+pub fn elm_format(_path: &Path, contents: &str) -> io::Result<String> {
+    run(Command::new("elm-format").arg("--stdin"), contents)
+}
+
+/// Format the file using [clang-format](https://clang.llvm.org/docs/ClangFormat.html).
+pub fn clang_format(path: &Path, contents: &str) -> io::Result<String> {
+    run(
+        Command::new("clang-format").arg(format!("--assume-filename={}", path.display())),
+        contents,
+    )
+}
+
+/// Run a formatter which reads the file from stdin and writes the formatted file to stdout.
+fn run(cmd: &mut Command, contents: &str) -> io::Result<String> {
+    let program = cmd.get_program().to_string_lossy().to_string();
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| io::Error::new(err.kind(), format!("Failed to run `{program}`: {err}")))?;
+
+    // stdin is written from another thread while stdout and stderr are read so a formatter which fills the output pipes before it has read all of its input can't deadlock.
+    let stdin = child.stdin.take();
+    let contents = contents.to_string();
+    let writer = thread::spawn(move || match stdin {
+        Some(mut stdin) => stdin.write_all(contents.as_bytes()),
+        None => Ok(()),
+    });
+
+    let output = child.wait_with_output()?;
+    let written = writer
+        .join()
+        .map_err(|_| io::Error::other(format!("Failed to write to `{program}`")))?;
+
+    // A formatter which fails can exit before reading all of its input so the exit status is more useful than the write error.
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "`{program}` exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    written?;
+
+    String::from_utf8(output.stdout).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
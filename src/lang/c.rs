@@ -0,0 +1,368 @@
+use thiserror::Error;
+
+use crate::*;
+
+/// The headers required by the declarations generated by this exporter.
+pub const INCLUDES: &str = "#include <stdbool.h>\n#include <stdint.h>";
+
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum CExportError {
+    #[error("Failed to export type '{}' on field `{}`: {err}", .ty_name.unwrap_or_default(), .field_name.unwrap_or_default())]
+    WithCtx {
+        ty_name: Option<&'static str>,
+        field_name: Option<&'static str>,
+        err: Box<CExportError>,
+    },
+    #[error("Type '{0}' does not have a stable memory layout. Mark it with `#[specta(ffi)]` (and `#[repr(C)]`) to export it to C.")]
+    NotFfi(&'static str),
+    #[error("Type '{0}' is referenced but isn't being exported so its memory layout can't be checked. Export it alongside the types which reference it.")]
+    UnknownReference(&'static str),
+    #[error("Types {0:?} contain each other by value so they would have an infinite size. Try using a raw pointer instead.")]
+    RecursiveType(Vec<&'static str>),
+    #[error("Type '{0}' is heap allocated so it can't be shared across an FFI boundary. Try using a raw pointer and length instead.")]
+    HeapType(&'static str),
+    #[error("Cannot export anonymous object. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousObject,
+    #[error("You have defined a type with the name '{0}' which is not a valid C identifier. Try renaming it or using `#[specta(rename = \"new name\")]`")]
+    ForbiddenTypeName(&'static str),
+    #[error("You have defined a field '{1}' on type '{0}' which is not a valid C identifier. Try renaming it or using `#[specta(rename = \"new name\")]`")]
+    ForbiddenFieldName(String, &'static str),
+    #[error("Type cannot be exported: {0:?}")]
+    CannotExport(Box<DataTypeExt>),
+    #[error("C does not support {0}")]
+    Unsupported(&'static str),
+    #[error("Cannot export type due to an internal error. This likely is a bug in Specta itself and not your code: {0}")]
+    InternalError(&'static str),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// The [C](https://en.wikipedia.org/wiki/C_(programming_language)) [`Language`](crate::Language).
+pub struct C;
+
+/// Only the types marked with `#[specta(ffi)]` are exported when exporting many types as the others don't have a stable memory layout.
+/// A type can only reference other `#[specta(ffi)]` types which are exported alongside it.
+/// Types are declared after the types they contain as C doesn't allow a type to be used by value before it's declared.
+impl Language for C {
+    type Config = ();
+    type Error = CExportError;
+
+    const HEADER: Option<&'static str> = Some(INCLUDES);
+
+    fn export_datatype(_conf: &Self::Config, def: &DataTypeExt) -> Result<String, Self::Error> {
+        export_datatype(&TypeDefs::from([(def.name, def.clone())]), def)
+    }
+
+    fn export_type<T: Type>(_conf: &Self::Config) -> Result<String, Self::Error> {
+        export::<T>()
+    }
+
+    fn export_many<'a>(
+        _conf: &Self::Config,
+        types: impl IntoIterator<Item = &'a DataTypeExt>,
+    ) -> Result<String, Self::Error> {
+        let type_map = types
+            .into_iter()
+            .filter(|def| def.ffi)
+            .map(|def| (def.name, def.clone()))
+            .collect::<TypeDefs>();
+
+        Ok(dependency_order(&type_map)
+            .into_iter()
+            .map(|group| match &group[..] {
+                [name] if !type_map[name].inner.references().contains(name) => {
+                    export_datatype(&type_map, &type_map[name])
+                }
+                _ => Err(CExportError::RecursiveType(group)),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .join("\n\n"))
+    }
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to a C declaration.
+/// The type must be marked with `#[specta(ffi)]`.
+/// Eg. `typedef struct Foo { int32_t demo; } Foo;`
+pub fn export<T: Type>() -> Result<String, CExportError> {
+    let mut type_map = TypeDefs::default();
+    let def = T::definition(DefOpts {
+        parent_inline: true,
+        type_map: &mut type_map,
+    });
+
+    export_datatype(&type_map, &def)
+}
+
+/// Convert a DataType to a C declaration.
+/// References are checked against the types in `type_map` as they must also be marked with `#[specta(ffi)]`.
+///
+/// Structs are exported as `typedef struct`, enums containing only unit variants as `typedef enum`
+/// and enums with fields as a tagged union following the layout of a `#[repr(C)]` Rust enum.
+pub fn export_datatype(type_map: &TypeDefs, def: &DataTypeExt) -> Result<String, CExportError> {
+    if !def.ffi {
+        return Err(CExportError::NotFfi(def.name));
+    }
+
+    let name = def.name;
+    if !is_valid_ident(name) {
+        return Err(CExportError::ForbiddenTypeName(name));
+    }
+
+    export_declaration(type_map, name, &def.inner).map_err(|err| CExportError::WithCtx {
+        ty_name: Some(name),
+        field_name: None,
+        err: Box::new(err),
+    })
+}
+
+fn export_declaration(
+    type_map: &TypeDefs,
+    name: &'static str,
+    typ: &DataType,
+) -> Result<String, CExportError> {
+    Ok(match typ {
+        DataType::Object(ObjectType {
+            name: obj_name,
+            generics,
+            fields,
+            ..
+        }) => {
+            if obj_name.is_empty() {
+                return Err(CExportError::AnonymousObject);
+            } else if !generics.is_empty() {
+                return Err(CExportError::Unsupported("generic types"));
+            }
+
+            struct_declaration(name, &object_fields(type_map, name, fields)?)?
+        }
+        DataType::Tuple(TupleType {
+            generics, fields, ..
+        }) => {
+            if !generics.is_empty() {
+                return Err(CExportError::Unsupported("generic types"));
+            }
+
+            struct_declaration(name, &tuple_fields(type_map, fields)?)?
+        }
+        DataType::Enum(EnumType {
+            generics, variants, ..
+        }) => {
+            if !generics.is_empty() {
+                return Err(CExportError::Unsupported("generic types"));
+            } else if variants.is_empty() {
+                return Err(CExportError::Unsupported("enums without any variants"));
+            }
+
+            let tags = variants
+                .iter()
+                .map(|v| variant_ident(name, v.name()))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if variants.iter().all(|v| matches!(v, EnumVariant::Unit(..))) {
+                return Ok(enum_declaration(name, &tags));
+            }
+
+            let tag_name = format!("{name}_Tag");
+            let mut decls = vec![enum_declaration(&tag_name, &tags)];
+            let mut members = vec![];
+
+            for (variant, tag) in variants.iter().zip(tags) {
+                let fields = match variant {
+                    EnumVariant::Unit(..) => continue,
+                    EnumVariant::Unnamed(TupleType { fields, .. }) => {
+                        tuple_fields(type_map, fields)?
+                    }
+                    EnumVariant::Named(ObjectType { fields, .. }) => {
+                        object_fields(type_map, name, fields)?
+                    }
+                };
+
+                if fields.is_empty() {
+                    continue;
+                }
+
+                let member = variant.name().to_lowercase();
+                if !is_valid_ident(&member) {
+                    return Err(CExportError::ForbiddenFieldName(
+                        name.to_owned(),
+                        variant.name(),
+                    ));
+                }
+
+                let body_name = format!("{tag}_Body");
+                decls.push(struct_declaration(&body_name, &fields)?);
+                members.push(format!("{body_name} {member};"));
+            }
+
+            decls.push(format!(
+                "typedef struct {name} {{\n    {tag_name} tag;\n    union {{\n        {}\n    }};\n}} {name};",
+                members.join("\n        ")
+            ));
+
+            decls.join("\n\n")
+        }
+        // Eg. a `#[repr(transparent)]` newtype
+        typ => format!("typedef {} {name};", datatype(type_map, typ)?),
+    })
+}
+
+/// Convert a DataType to a C type. References are checked against the types in `type_map`.
+/// Eg. `int32_t`
+pub fn datatype(type_map: &TypeDefs, typ: &DataType) -> Result<String, CExportError> {
+    Ok(match typ {
+        DataType::Any => return Err(CExportError::Unsupported("untyped values")),
+        DataType::Primitive(p) => match p {
+            PrimitiveType::i8 => "int8_t",
+            PrimitiveType::i16 => "int16_t",
+            PrimitiveType::i32 => "int32_t",
+            PrimitiveType::i64 => "int64_t",
+            PrimitiveType::isize => "intptr_t",
+            PrimitiveType::u8 => "uint8_t",
+            PrimitiveType::u16 => "uint16_t",
+            PrimitiveType::u32 => "uint32_t",
+            PrimitiveType::u64 => "uint64_t",
+            PrimitiveType::usize => "uintptr_t",
+            PrimitiveType::f32 => "float",
+            PrimitiveType::f64 => "double",
+            PrimitiveType::bool => "bool",
+            // A Rust `char` is a 32 bit unicode scalar value
+            PrimitiveType::char => "uint32_t",
+            PrimitiveType::i128 | PrimitiveType::u128 => {
+                return Err(CExportError::Unsupported("128 bit numbers"))
+            }
+            PrimitiveType::String => return Err(CExportError::HeapType("String")),
+        }
+        .to_string(),
+        DataType::List(_) => return Err(CExportError::HeapType("List")),
+        DataType::Set(_) => return Err(CExportError::HeapType("Set")),
+        DataType::Record(_) => return Err(CExportError::HeapType("Record")),
+        DataType::Literal(_) => return Err(CExportError::Unsupported("literal types")),
+        DataType::WellKnown(ty) => datatype(type_map, &ty.repr())?,
+        DataType::Nullable(_) => return Err(CExportError::Unsupported("nullable types")),
+        DataType::Tuple(_) => {
+            return Err(CExportError::Unsupported(
+                "tuples as they don't have a stable memory layout",
+            ))
+        }
+        DataType::Object(ObjectType { name, fields, .. }) => match &fields[..] {
+            [] => return Err(CExportError::Unsupported("zero sized types")),
+            fields => format!(
+                "struct {{ {} }}",
+                object_fields(type_map, name, fields)?
+                    .iter()
+                    .map(|(name, ty)| format!("{ty} {name};"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+        },
+        DataType::Enum(_) => return Err(CExportError::Unsupported("inline enums")),
+        DataType::Reference { name, generics, .. } => match (&generics[..], type_map.get(name)) {
+            ([], Some(def)) if def.ffi => name.to_string(),
+            ([], Some(_)) => return Err(CExportError::NotFfi(name)),
+            ([], None) => return Err(CExportError::UnknownReference(name)),
+            _ => return Err(CExportError::Unsupported("generic types")),
+        },
+        DataType::Generic(_) => return Err(CExportError::Unsupported("generic types")),
+        DataType::Placeholder => {
+            return Err(CExportError::InternalError(
+                "Attempted to export a placeholder!",
+            ))
+        }
+    })
+}
+
+fn struct_declaration(name: &str, fields: &[(String, String)]) -> Result<String, CExportError> {
+    if fields.is_empty() {
+        return Err(CExportError::Unsupported("zero sized types"));
+    }
+
+    Ok(format!(
+        "typedef struct {name} {{\n{}}} {name};",
+        fields
+            .iter()
+            .map(|(name, ty)| format!("    {ty} {name};\n"))
+            .collect::<String>()
+    ))
+}
+
+fn enum_declaration(name: &str, variants: &[String]) -> String {
+    format!(
+        "typedef enum {name} {{\n{}}} {name};",
+        variants
+            .iter()
+            .map(|v| format!("    {v},\n"))
+            .collect::<String>()
+    )
+}
+
+fn object_fields(
+    type_map: &TypeDefs,
+    type_name: &str,
+    fields: &[ObjectField],
+) -> Result<Vec<(String, String)>, CExportError> {
+    fields
+        .iter()
+        .map(|field| {
+            if field.flatten {
+                return Err(CExportError::Unsupported("flattened fields"));
+            } else if !is_valid_ident(field.name) {
+                return Err(CExportError::ForbiddenFieldName(
+                    type_name.to_owned(),
+                    field.name,
+                ));
+            }
+
+            datatype(type_map, &field.ty)
+                .map(|ty| (field.name.to_string(), ty))
+                .map_err(|err| CExportError::WithCtx {
+                    ty_name: None,
+                    field_name: Some(field.name),
+                    err: Box::new(err),
+                })
+        })
+        .collect()
+}
+
+fn tuple_fields(
+    type_map: &TypeDefs,
+    fields: &[DataType],
+) -> Result<Vec<(String, String)>, CExportError> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| datatype(type_map, ty).map(|ty| (format!("_{i}"), ty)))
+        .collect()
+}
+
+fn variant_ident(
+    type_name: &'static str,
+    variant_name: &'static str,
+) -> Result<String, CExportError> {
+    let ident = format!("{type_name}_{variant_name}");
+    match is_valid_ident(&ident) {
+        true => Ok(ident),
+        false => Err(CExportError::ForbiddenFieldName(
+            type_name.to_owned(),
+            variant_name,
+        )),
+    }
+}
+
+fn is_valid_ident(ident: &str) -> bool {
+    ident.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && ident
+            .chars()
+            .next()
+            .map(|first| !first.is_ascii_digit())
+            .unwrap_or(false)
+        && !RESERVED_WORDS.contains(&ident)
+}
+
+// Taken from: https://en.cppreference.com/w/c/keyword
+const RESERVED_WORDS: &[&str] = &[
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else",
+    "enum", "extern", "float", "for", "goto", "if", "inline", "int", "long", "register",
+    "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch", "typedef",
+    "union", "unsigned", "void", "volatile", "while", "bool", "true", "false",
+];
@@ -0,0 +1,877 @@
+use thiserror::Error;
+
+use crate::*;
+
+use super::variant_type_name;
+
+/// Allows you to control the behavior of the Elm exporter
+#[derive(Default)]
+pub struct ExportConfiguration {
+    /// control how bigint types are exported
+    bigint: BigIntExportBehavior,
+    /// the name of the module declared at the start of exported files
+    module: Option<String>,
+}
+
+impl ExportConfiguration {
+    /// Construct a new `ExportConfiguration`
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configure the BigInt handling behaviour
+    pub fn bigint(mut self, bigint: BigIntExportBehavior) -> Self {
+        self.bigint = bigint;
+        self
+    }
+
+    /// Configure the name of the module declared at the start of files exported with [`Export`](crate::export::Export). Defaults to `Main`.
+    pub fn module(mut self, module: impl Into<String>) -> Self {
+        self.module = Some(module.into());
+        self
+    }
+}
+
+/// Allows you to configure how Specta's Elm exporter will deal with BigInt types (i64 u64 isize usize i128 u128).
+#[derive(Default)]
+pub enum BigIntExportBehavior {
+    /// Export BigInt as an Elm `String`
+    /// WARNING: Specta takes no responsibility that the Rust number is encoded as a string.
+    /// Make sure you instruct serde <https://github.com/serde-rs/json/issues/329#issuecomment-305608405> or your other serializer of this.
+    String,
+    /// Export BigInt as an Elm `Int`.
+    /// WARNING: Elm decodes JSON numbers into Javascript numbers so values above 2^53 will lose precision.
+    Int,
+    /// Abort the export with an error
+    /// This is the default behavior because without integration from your serializer we can't guarantee data loss won't occur.
+    #[default]
+    Fail,
+    /// Same as `Self::Fail` but it allows a library to configure the message shown to the end user.
+    #[doc(hidden)]
+    FailWithReason(&'static str),
+}
+
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum ElmExportError {
+    #[error("Failed to export type '{}' on field `{}`: {err}", .ty_name.unwrap_or_default(), .field_name.unwrap_or_default())]
+    WithCtx {
+        ty_name: Option<&'static str>,
+        field_name: Option<&'static str>,
+        err: Box<ElmExportError>,
+    },
+    #[error("Cannot export anonymous object. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousObject,
+    #[error("Cannot export anonymous enum. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousEnum,
+    #[error("You have defined a type with the name '{0}' which is not a valid Elm type name. Try renaming it or using `#[specta(rename = \"NewName\")]`")]
+    ForbiddenTypeName(&'static str),
+    #[error("You have defined a field '{1}' on type '{0}' which is not a valid Elm record field name. Try renaming it or using `#[specta(rename = \"new_name\")]`")]
+    ForbiddenFieldName(String, &'static str),
+    #[error("Type cannot be exported: {0:?}")]
+    CannotExport(Box<DataTypeExt>),
+    #[error("Your Specta configuration forbids exporting BigInt types (i64, u64, isize, usize, i128, u128) because Elm can't represent them without losing precision. You can change this behavior by editing your `ExportConfiguration`")]
+    BigIntForbidden,
+    #[error("Elm does not support {0}")]
+    Unsupported(&'static str),
+    #[error("Cannot export type due to an internal error. This likely is a bug in Specta itself and not your code: {0}")]
+    InternalError(&'static str),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Other(String),
+}
+
+/// The module declaration and imports required by the code generated by this exporter.
+pub fn module_header(module_name: &str) -> String {
+    format!("module {module_name} exposing (..)\n\nimport Dict exposing (Dict)\nimport Json.Decode as D\nimport Json.Encode as E")
+}
+
+/// The [Elm](https://elm-lang.org) [`Language`](crate::Language).
+pub struct Elm;
+
+impl Language for Elm {
+    type Config = ExportConfiguration;
+    type Error = ElmExportError;
+
+    fn header(conf: &Self::Config) -> Option<String> {
+        Some(module_header(conf.module.as_deref().unwrap_or("Main")))
+    }
+
+    fn export_datatype(conf: &Self::Config, def: &DataTypeExt) -> Result<String, Self::Error> {
+        export_datatype(conf, def)
+    }
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to an Elm type declaration along with its `decode{Name}` and `encode{Name}` functions.
+pub fn export<T: Type>(conf: &ExportConfiguration) -> Result<String, ElmExportError> {
+    export_datatype(
+        conf,
+        &T::definition(DefOpts {
+            parent_inline: true,
+            type_map: &mut TypeDefs::default(),
+        }),
+    )
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to an Elm type expression.
+/// Eg. `{ demo : String }`
+pub fn inline<T: Type>(conf: &ExportConfiguration) -> Result<String, ElmExportError> {
+    datatype(
+        conf,
+        &T::inline(
+            DefOpts {
+                parent_inline: true,
+                type_map: &mut TypeDefs::default(),
+            },
+            &[],
+        ),
+    )
+}
+
+/// Convert a DataType to an Elm type declaration along with its `decode{Name}` and `encode{Name}` functions.
+///
+/// The decoders and encoders follow the JSON representation produced by serde,
+/// including the tagging of enums and omission of optional fields.
+pub fn export_datatype(
+    conf: &ExportConfiguration,
+    def: &DataTypeExt,
+) -> Result<String, ElmExportError> {
+    let (name, generics) = match &def.inner {
+        DataType::Object(ObjectType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(ElmExportError::AnonymousObject);
+            }
+
+            (*name, generics)
+        }
+        DataType::Enum(EnumType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(ElmExportError::AnonymousEnum);
+            }
+
+            (*name, generics)
+        }
+        DataType::Tuple(TupleType { name, generics, .. }) => (*name, generics),
+        _ => return Err(ElmExportError::CannotExport(Box::new(def.clone()))),
+    };
+
+    if !is_valid_type_name(name) {
+        return Err(ElmExportError::ForbiddenTypeName(name));
+    }
+
+    export_declaration(conf, name, generics, &def.inner).map_err(|err| ElmExportError::WithCtx {
+        ty_name: Some(def.name),
+        field_name: None,
+        err: Box::new(err),
+    })
+}
+
+fn export_declaration(
+    conf: &ExportConfiguration,
+    name: &'static str,
+    generics: &[&'static str],
+    typ: &DataType,
+) -> Result<String, ElmExportError> {
+    let type_vars = generics
+        .iter()
+        .map(|g| format!(" {}", type_var(g)))
+        .collect::<String>();
+    let full_type = match generics.len() {
+        0 => name.to_string(),
+        _ => format!("({name}{type_vars})"),
+    };
+
+    let declaration = match typ {
+        DataType::Enum(EnumType { variants, .. }) => match &variants[..] {
+            [] => return Err(ElmExportError::Unsupported("enums without any variants")),
+            variants => {
+                let variants = variants
+                    .iter()
+                    .map(|variant| {
+                        let ctor = variant_type_name(name, variant.name());
+
+                        Ok(match variant {
+                            EnumVariant::Unit(..) => ctor,
+                            EnumVariant::Unnamed(TupleType { fields, .. }) => fields
+                                .iter()
+                                .map(|ty| datatype(conf, ty).map(|ty| format!(" {}", paren(ty))))
+                                .collect::<Result<Vec<_>, _>>()
+                                .map(|fields| format!("{ctor}{}", fields.join("")))?,
+                            EnumVariant::Named(obj) => {
+                                format!("{ctor} {}", object_datatype(conf, name, &obj.fields)?)
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>, ElmExportError>>()?;
+
+                format!(
+                    "type {name}{type_vars}\n    = {}",
+                    variants.join("\n    | ")
+                )
+            }
+        },
+        DataType::Object(ObjectType { fields, .. }) if !fields.is_empty() => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    object_field_datatype(conf, name, field)
+                        .map(|(key, ty)| format!("{key} : {ty}"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            format!(
+                "type alias {name}{type_vars} =\n    {{ {}\n    }}",
+                fields.join("\n    , ")
+            )
+        }
+        typ => format!(
+            "type alias {name}{type_vars} =\n    {}",
+            datatype(conf, typ)?
+        ),
+    };
+
+    let decoder_args = generics
+        .iter()
+        .map(|g| format!("D.Decoder {} -> ", type_var(g)))
+        .collect::<String>();
+    let decoder_params = generics
+        .iter()
+        .map(|g| format!(" decode{g}"))
+        .collect::<String>();
+    let decoder = match typ {
+        DataType::Enum(e) => enum_decoder(conf, name, e)?,
+        DataType::Object(ObjectType { fields, .. }) if !fields.is_empty() => {
+            object_decoder(conf, None, fields, "\n        ")?
+        }
+        typ => decoder(conf, typ)?,
+    };
+
+    let encoder_args = generics
+        .iter()
+        .map(|g| format!("({} -> E.Value) -> ", type_var(g)))
+        .collect::<String>();
+    let encoder_params = generics
+        .iter()
+        .map(|g| format!(" encode{g}"))
+        .collect::<String>();
+    let encoder = match typ {
+        DataType::Enum(e) => enum_encoder(conf, name, e)?,
+        DataType::Object(ObjectType { fields, tag, .. }) if !fields.is_empty() => {
+            let tag = tag.map(|(tag, value)| format!("( \"{tag}\", E.string \"{value}\" )"));
+            object_encoder(conf, "value", fields, tag, 1)?
+        }
+        typ => format!("{} value", paren(encoder(conf, typ, 1)?)),
+    };
+
+    Ok(format!(
+        "{declaration}\n\n\ndecode{name} : {decoder_args}D.Decoder {full_type}\ndecode{name}{decoder_params} =\n    {decoder}\n\n\nencode{name} : {encoder_args}{full_type} -> E.Value\nencode{name}{encoder_params} value =\n    {encoder}"
+    ))
+}
+
+/// Convert a DataType to an Elm type expression
+/// Eg. `{ demo : String }`
+pub fn datatype(conf: &ExportConfiguration, typ: &DataType) -> Result<String, ElmExportError> {
+    Ok(match typ {
+        DataType::Any => "E.Value".into(),
+        primitive_def!(i8 i16 i32 u8 u16 u32) => "Int".into(),
+        primitive_def!(i64 isize u64 usize i128 u128) => bigint(conf, "Int", "String")?,
+        primitive_def!(f32 f64) => "Float".into(),
+        primitive_def!(String char) => "String".into(),
+        primitive_def!(bool) => "Bool".into(),
+        DataType::Literal(_) => return Err(ElmExportError::Unsupported("literal types")),
+        DataType::WellKnown(ty) => datatype(conf, &ty.repr())?,
+        DataType::Nullable(def) => format!("Maybe {}", paren(datatype(conf, def)?)),
+        DataType::Record(def) => {
+            record_key(&def.0)?;
+            format!("Dict String {}", paren(datatype(conf, &def.1)?))
+        }
+        // Elm's `Set` only holds comparable values so sets are exported as lists
+        DataType::List(def) | DataType::Set(def) => format!("List {}", paren(datatype(conf, def)?)),
+        DataType::Tuple(TupleType { fields, .. }) => match &fields[..] {
+            [] => "()".into(),
+            [ty] => datatype(conf, ty)?,
+            [_, _] | [_, _, _] => format!(
+                "( {} )",
+                fields
+                    .iter()
+                    .map(|ty| datatype(conf, ty))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            _ => return Err(ElmExportError::Unsupported("tuples with more than 3 items")),
+        },
+        DataType::Object(ObjectType { name, fields, .. }) => match &fields[..] {
+            [] => "()".into(),
+            fields => object_datatype(conf, name, fields)?,
+        },
+        DataType::Enum(_) => return Err(ElmExportError::Unsupported("inline enums")),
+        DataType::Reference { name, generics, .. } => match &generics[..] {
+            [] => name.to_string(),
+            generics => format!(
+                "{name} {}",
+                generics
+                    .iter()
+                    .map(|g| datatype(conf, g).map(paren))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(" ")
+            ),
+        },
+        DataType::Generic(GenericType(ident)) => type_var(ident),
+        DataType::Placeholder => {
+            return Err(ElmExportError::InternalError(
+                "Attempted to export a placeholder!",
+            ))
+        }
+    })
+}
+
+/// Convert a DataType to an Elm `Json.Decode.Decoder` expression
+/// Eg. `D.list D.string`
+pub fn decoder(conf: &ExportConfiguration, typ: &DataType) -> Result<String, ElmExportError> {
+    Ok(match typ {
+        DataType::Any => "D.value".into(),
+        primitive_def!(i8 i16 i32 u8 u16 u32) => "D.int".into(),
+        primitive_def!(i64 isize u64 usize i128 u128) => bigint(conf, "D.int", "D.string")?,
+        primitive_def!(f32 f64) => "D.float".into(),
+        primitive_def!(String char) => "D.string".into(),
+        primitive_def!(bool) => "D.bool".into(),
+        DataType::Literal(_) => return Err(ElmExportError::Unsupported("literal types")),
+        DataType::WellKnown(ty) => decoder(conf, &ty.repr())?,
+        DataType::Nullable(def) => format!("D.nullable {}", paren(decoder(conf, def)?)),
+        DataType::Record(def) => {
+            record_key(&def.0)?;
+            format!("D.dict {}", paren(decoder(conf, &def.1)?))
+        }
+        DataType::List(def) | DataType::Set(def) => {
+            format!("D.list {}", paren(decoder(conf, def)?))
+        }
+        DataType::Tuple(TupleType { fields, .. }) => match &fields[..] {
+            [] => "D.null ()".into(),
+            [ty] => decoder(conf, ty)?,
+            [a, b] => format!(
+                "D.map2 Tuple.pair (D.index 0 {}) (D.index 1 {})",
+                paren(decoder(conf, a)?),
+                paren(decoder(conf, b)?)
+            ),
+            [a, b, c] => format!(
+                "D.map3 (\\a b c -> ( a, b, c )) (D.index 0 {}) (D.index 1 {}) (D.index 2 {})",
+                paren(decoder(conf, a)?),
+                paren(decoder(conf, b)?),
+                paren(decoder(conf, c)?)
+            ),
+            _ => return Err(ElmExportError::Unsupported("tuples with more than 3 items")),
+        },
+        DataType::Object(ObjectType { fields, .. }) => match &fields[..] {
+            [] => "D.null ()".into(),
+            fields => object_decoder(conf, None, fields, " ")?,
+        },
+        DataType::Enum(_) => return Err(ElmExportError::Unsupported("inline enums")),
+        DataType::Reference { name, generics, .. } => match &generics[..] {
+            // `D.lazy` is required so recursive types don't create a cyclic value
+            [] => format!("D.lazy (\\_ -> decode{name})"),
+            generics => format!(
+                "decode{name} {}",
+                generics
+                    .iter()
+                    .map(|g| decoder(conf, g).map(paren))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(" ")
+            ),
+        },
+        DataType::Generic(GenericType(ident)) => format!("decode{ident}"),
+        DataType::Placeholder => {
+            return Err(ElmExportError::InternalError(
+                "Attempted to export a placeholder!",
+            ))
+        }
+    })
+}
+
+/// Convert a DataType to an Elm function expression which encodes a value into a `Json.Encode.Value`
+/// Eg. `E.list E.string`
+///
+/// `depth` is used to give variables bound by nested lambdas unique names as Elm doesn't allow shadowing.
+pub fn encoder(
+    conf: &ExportConfiguration,
+    typ: &DataType,
+    depth: usize,
+) -> Result<String, ElmExportError> {
+    Ok(match typ {
+        DataType::Any => "identity".into(),
+        primitive_def!(i8 i16 i32 u8 u16 u32) => "E.int".into(),
+        primitive_def!(i64 isize u64 usize i128 u128) => bigint(conf, "E.int", "E.string")?,
+        primitive_def!(f32 f64) => "E.float".into(),
+        primitive_def!(String char) => "E.string".into(),
+        primitive_def!(bool) => "E.bool".into(),
+        DataType::Literal(_) => return Err(ElmExportError::Unsupported("literal types")),
+        DataType::WellKnown(ty) => encoder(conf, &ty.repr(), depth)?,
+        DataType::Nullable(def) => format!(
+            "Maybe.map {} >> Maybe.withDefault E.null",
+            paren(encoder(conf, def, depth)?)
+        ),
+        DataType::Record(def) => {
+            record_key(&def.0)?;
+            format!("E.dict identity {}", paren(encoder(conf, &def.1, depth)?))
+        }
+        DataType::List(def) | DataType::Set(def) => {
+            format!("E.list {}", paren(encoder(conf, def, depth)?))
+        }
+        DataType::Tuple(TupleType { fields, .. }) => match &fields[..] {
+            [] => "\\_ -> E.null".into(),
+            [ty] => encoder(conf, ty, depth)?,
+            [_, _] | [_, _, _] => {
+                let vars = (0..fields.len())
+                    .map(|i| format!("t{depth}_{i}"))
+                    .collect::<Vec<_>>();
+
+                format!(
+                    "\\( {} ) -> E.list identity [ {} ]",
+                    vars.join(", "),
+                    fields
+                        .iter()
+                        .zip(&vars)
+                        .map(|(ty, var)| encoder(conf, ty, depth + 1)
+                            .map(|enc| format!("{} {var}", paren(enc))))
+                        .collect::<Result<Vec<_>, _>>()?
+                        .join(", ")
+                )
+            }
+            _ => return Err(ElmExportError::Unsupported("tuples with more than 3 items")),
+        },
+        DataType::Object(ObjectType { fields, tag, .. }) => match &fields[..] {
+            [] => "\\_ -> E.null".into(),
+            fields => {
+                let var = format!("v{depth}");
+                let tag = tag.map(|(tag, value)| format!("( \"{tag}\", E.string \"{value}\" )"));
+
+                format!(
+                    "\\{var} -> {}",
+                    object_encoder(conf, &var, fields, tag, depth + 1)?
+                )
+            }
+        },
+        DataType::Enum(_) => return Err(ElmExportError::Unsupported("inline enums")),
+        DataType::Reference { name, generics, .. } => match &generics[..] {
+            [] => format!("encode{name}"),
+            generics => format!(
+                "encode{name} {}",
+                generics
+                    .iter()
+                    .map(|g| encoder(conf, g, depth).map(paren))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(" ")
+            ),
+        },
+        DataType::Generic(GenericType(ident)) => format!("encode{ident}"),
+        DataType::Placeholder => {
+            return Err(ElmExportError::InternalError(
+                "Attempted to export a placeholder!",
+            ))
+        }
+    })
+}
+
+fn enum_decoder(
+    conf: &ExportConfiguration,
+    name: &str,
+    e: &EnumType,
+) -> Result<String, ElmExportError> {
+    let branches = |payload: &dyn Fn(&EnumVariant, String) -> Result<String, ElmExportError>| {
+        e.variants
+            .iter()
+            .map(|variant| {
+                let ctor = variant_type_name(name, variant.name());
+                Ok(format!(
+                    "\"{}\" ->\n                        {}",
+                    variant.name(),
+                    payload(variant, ctor)?
+                ))
+            })
+            .collect::<Result<Vec<_>, ElmExportError>>()
+            .map(|branches| {
+                format!(
+                    "{}\n\n                    _ ->\n                        D.fail (\"Unknown variant '\" ++ tag ++ \"' of {name}\")",
+                    branches.join("\n\n                    ")
+                )
+            })
+    };
+
+    Ok(match &e.repr {
+        EnumRepr::External => {
+            let variants = e
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ctor = variant_type_name(name, variant.name());
+                    let variant_name = variant.name();
+
+                    Ok(match variant {
+                        EnumVariant::Unit(..) => format!("D.string |> D.andThen (\\tag -> if tag == \"{variant_name}\" then D.succeed {ctor} else D.fail \"Expected {variant_name}\")"),
+                        variant => format!("D.field \"{variant_name}\" {}", paren(variant_decoder(conf, &ctor, variant)?)),
+                    })
+                })
+                .collect::<Result<Vec<_>, ElmExportError>>()?;
+
+            format!("D.oneOf\n        [ {}\n        ]", variants.join("\n        , "))
+        }
+        EnumRepr::Internal { tag } => format!(
+            "D.field \"{tag}\" D.string\n        |> D.andThen\n            (\\tag ->\n                case tag of\n                    {}\n            )",
+            branches(&|variant, ctor| variant_decoder(conf, &ctor, variant))?
+        ),
+        EnumRepr::Adjacent { tag, content } => format!(
+            "D.field \"{tag}\" D.string\n        |> D.andThen\n            (\\tag ->\n                case tag of\n                    {}\n            )",
+            branches(&|variant, ctor| Ok(match variant {
+                EnumVariant::Unit(..) => variant_decoder(conf, &ctor, variant)?,
+                variant => format!("D.field \"{content}\" {}", paren(variant_decoder(conf, &ctor, variant)?)),
+            }))?
+        ),
+        EnumRepr::Untagged => {
+            let variants = e
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ctor = variant_type_name(name, variant.name());
+
+                    Ok(match variant {
+                        EnumVariant::Unit(..) => format!("D.null {ctor}"),
+                        variant => variant_decoder(conf, &ctor, variant)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, ElmExportError>>()?;
+
+            format!("D.oneOf\n        [ {}\n        ]", variants.join("\n        , "))
+        }
+    })
+}
+
+fn variant_decoder(
+    conf: &ExportConfiguration,
+    ctor: &str,
+    variant: &EnumVariant,
+) -> Result<String, ElmExportError> {
+    Ok(match variant {
+        EnumVariant::Unit(..) => format!("D.succeed {ctor}"),
+        EnumVariant::Unnamed(TupleType { fields, .. }) => match &fields[..] {
+            [] => format!("D.succeed {ctor}"),
+            [ty] => format!("D.map {ctor} {}", paren(decoder(conf, ty)?)),
+            fields => format!(
+                "D.succeed {ctor}{}",
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, ty)| decoder(conf, ty)
+                        .map(|dec| format!(" |> D.map2 (|>) (D.index {i} {})", paren(dec))))
+                    .collect::<Result<String, _>>()?
+            ),
+        },
+        EnumVariant::Named(obj) => object_decoder(conf, Some(ctor), &obj.fields, " ")?,
+    })
+}
+
+fn enum_encoder(
+    conf: &ExportConfiguration,
+    name: &str,
+    e: &EnumType,
+) -> Result<String, ElmExportError> {
+    let branches = e
+        .variants
+        .iter()
+        .map(|variant| {
+            let ctor = variant_type_name(name, variant.name());
+            let variant_name = variant.name();
+
+            let (pattern, payload) = match variant {
+                EnumVariant::Unit(..) => (ctor, None),
+                EnumVariant::Unnamed(TupleType { fields, .. }) => {
+                    let vars = (0..fields.len())
+                        .map(|i| format!("p{i}"))
+                        .collect::<Vec<_>>();
+
+                    let payload = match &fields[..] {
+                        [ty] => format!("{} p0", paren(encoder(conf, ty, 1)?)),
+                        [] => "E.list identity []".into(),
+                        fields => format!(
+                            "E.list identity [ {} ]",
+                            fields
+                                .iter()
+                                .zip(&vars)
+                                .map(|(ty, var)| encoder(conf, ty, 1)
+                                    .map(|enc| format!("{} {var}", paren(enc))))
+                                .collect::<Result<Vec<_>, _>>()?
+                                .join(", ")
+                        ),
+                    };
+
+                    (
+                        vars.iter().fold(ctor, |acc, var| format!("{acc} {var}")),
+                        Some(payload),
+                    )
+                }
+                EnumVariant::Named(obj) => (
+                    format!("{ctor} p"),
+                    Some(object_encoder(conf, "p", &obj.fields, None, 1)?),
+                ),
+            };
+
+            let body = match (&e.repr, payload) {
+                (EnumRepr::External, None) => format!("E.string \"{variant_name}\""),
+                (EnumRepr::External, Some(payload)) => {
+                    format!("E.object [ ( \"{variant_name}\", {payload} ) ]")
+                }
+                (EnumRepr::Internal { tag }, None) => {
+                    format!("E.object [ ( \"{tag}\", E.string \"{variant_name}\" ) ]")
+                }
+                (EnumRepr::Internal { tag }, Some(_)) => match variant {
+                    EnumVariant::Named(obj) => format!(
+                        "E.object ([ ( \"{tag}\", E.string \"{variant_name}\" ) ] ++ {})",
+                        object_encoder_fields(conf, "p", &obj.fields, 1)?
+                    ),
+                    _ => {
+                        return Err(ElmExportError::Unsupported(
+                            "encoding internally tagged tuple variants",
+                        ))
+                    }
+                },
+                (EnumRepr::Adjacent { tag, .. }, None) => {
+                    format!("E.object [ ( \"{tag}\", E.string \"{variant_name}\" ) ]")
+                }
+                (EnumRepr::Adjacent { tag, content }, Some(payload)) => format!(
+                    "E.object [ ( \"{tag}\", E.string \"{variant_name}\" ), ( \"{content}\", {payload} ) ]"
+                ),
+                (EnumRepr::Untagged, None) => "E.null".into(),
+                (EnumRepr::Untagged, Some(payload)) => payload,
+            };
+
+            Ok(format!("{pattern} ->\n            {body}"))
+        })
+        .collect::<Result<Vec<_>, ElmExportError>>()?;
+
+    Ok(format!(
+        "case value of\n        {}",
+        branches.join("\n\n        ")
+    ))
+}
+
+fn object_datatype(
+    conf: &ExportConfiguration,
+    type_name: &str,
+    fields: &[ObjectField],
+) -> Result<String, ElmExportError> {
+    Ok(match fields {
+        [] => "{}".into(),
+        fields => format!(
+            "{{ {} }}",
+            fields
+                .iter()
+                .map(|field| object_field_datatype(conf, type_name, field)
+                    .map(|(key, ty)| format!("{key} : {ty}")))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        ),
+    })
+}
+
+fn object_field_datatype(
+    conf: &ExportConfiguration,
+    type_name: &str,
+    field: &ObjectField,
+) -> Result<(&'static str, String), ElmExportError> {
+    if field.flatten {
+        return Err(ElmExportError::Unsupported("flattened fields"));
+    } else if !is_valid_field_name(field.name) {
+        return Err(ElmExportError::ForbiddenFieldName(
+            type_name.to_owned(),
+            field.name,
+        ));
+    }
+
+    let ty = match (field.optional, &field.ty) {
+        (true, DataType::Nullable(_)) | (false, _) => datatype(conf, &field.ty),
+        (true, ty) => datatype(conf, ty).map(|ty| format!("Maybe {}", paren(ty))),
+    }
+    .map_err(|err| ElmExportError::WithCtx {
+        ty_name: None,
+        field_name: Some(field.name),
+        err: Box::new(err),
+    })?;
+
+    Ok((field.name, ty))
+}
+
+fn object_decoder(
+    conf: &ExportConfiguration,
+    ctor: Option<&str>,
+    fields: &[ObjectField],
+    sep: &str,
+) -> Result<String, ElmExportError> {
+    let vars = (0..fields.len())
+        .map(|i| format!("a{i}"))
+        .collect::<Vec<_>>();
+
+    let record = format!(
+        "{{ {} }}",
+        fields
+            .iter()
+            .zip(&vars)
+            .map(|(field, var)| format!("{} = {var}", field.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let record = match fields {
+        [] => "{}".into(),
+        _ => record,
+    };
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let name = field.name;
+            let dec = match field.optional {
+                true => decoder(conf, unwrap_nullable(&field.ty)).map(|dec| {
+                    format!(
+                        "D.map (Maybe.andThen identity) (D.maybe (D.field \"{name}\" (D.nullable {})))",
+                        paren(dec)
+                    )
+                }),
+                false => decoder(conf, &field.ty)
+                    .map(|dec| format!("D.field \"{name}\" {}", paren(dec))),
+            }
+            .map_err(|err| ElmExportError::WithCtx {
+                ty_name: None,
+                field_name: Some(field.name),
+                err: Box::new(err),
+            })?;
+
+            Ok(format!("{sep}|> D.map2 (|>) ({dec})"))
+        })
+        .collect::<Result<String, ElmExportError>>()?;
+
+    let build = match ctor {
+        Some(ctor) => format!("{ctor} {record}"),
+        None => record,
+    };
+
+    Ok(match vars.len() {
+        0 => format!("D.succeed ({build})"),
+        _ => format!("D.succeed (\\{} -> {build}){fields}", vars.join(" ")),
+    })
+}
+
+fn object_encoder(
+    conf: &ExportConfiguration,
+    var: &str,
+    fields: &[ObjectField],
+    tag: Option<String>,
+    depth: usize,
+) -> Result<String, ElmExportError> {
+    let fields = object_encoder_fields(conf, var, fields, depth)?;
+
+    Ok(match tag {
+        Some(tag) => format!("E.object ({fields} ++ [ {tag} ])"),
+        None if fields.starts_with('[') => format!("E.object {fields}"),
+        None => format!("E.object ({fields})"),
+    })
+}
+
+fn object_encoder_fields(
+    conf: &ExportConfiguration,
+    var: &str,
+    fields: &[ObjectField],
+    depth: usize,
+) -> Result<String, ElmExportError> {
+    let has_optional = fields.iter().any(|f| f.optional);
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let name = field.name;
+            match field.optional {
+                true => encoder(conf, unwrap_nullable(&field.ty), depth).map(|enc| {
+                    format!(
+                        "Maybe.map (\\o{depth} -> ( \"{name}\", {} o{depth} )) {var}.{name}",
+                        paren(enc)
+                    )
+                }),
+                false => encoder(conf, &field.ty, depth).map(|enc| match has_optional {
+                    true => format!("Just ( \"{name}\", {} {var}.{name} )", paren(enc)),
+                    false => format!("( \"{name}\", {} {var}.{name} )", paren(enc)),
+                }),
+            }
+            .map_err(|err| ElmExportError::WithCtx {
+                ty_name: None,
+                field_name: Some(field.name),
+                err: Box::new(err),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match has_optional {
+        true => format!("List.filterMap identity [ {} ]", fields.join(", ")),
+        false => format!("[ {} ]", fields.join(", ")),
+    })
+}
+
+/// Pick the type, decoder or encoder of a BigInt type for the configured [`BigIntExportBehavior`].
+fn bigint(conf: &ExportConfiguration, int: &str, string: &str) -> Result<String, ElmExportError> {
+    match conf.bigint {
+        BigIntExportBehavior::String => Ok(string.into()),
+        BigIntExportBehavior::Int => Ok(int.into()),
+        BigIntExportBehavior::Fail => Err(ElmExportError::BigIntForbidden),
+        BigIntExportBehavior::FailWithReason(reason) => {
+            Err(ElmExportError::Other(reason.to_owned()))
+        }
+    }
+}
+
+fn unwrap_nullable(ty: &DataType) -> &DataType {
+    match ty {
+        DataType::Nullable(ty) => ty,
+        ty => ty,
+    }
+}
+
+fn record_key(key: &DataType) -> Result<(), ElmExportError> {
+    match key {
+        primitive_def!(String) => Ok(()),
+        DataType::WellKnown(ty) => record_key(&ty.repr()),
+        _ => Err(ElmExportError::Unsupported(
+            "dictionaries with non-string keys",
+        )),
+    }
+}
+
+/// Wrap an Elm expression in parentheses if it would otherwise be ambiguous when used as an argument.
+fn paren(s: String) -> String {
+    if s.contains(' ') && !(s.starts_with('(') && s.ends_with(')')) && !s.starts_with('{') {
+        format!("({s})")
+    } else {
+        s
+    }
+}
+
+/// Elm type variables must start with a lowercase letter.
+fn type_var(ident: &str) -> String {
+    let mut chars = ident.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn is_valid_type_name(name: &str) -> bool {
+    name.chars().next().map(char::is_uppercase).unwrap_or(false)
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn is_valid_field_name(name: &str) -> bool {
+    name.chars().next().map(char::is_lowercase).unwrap_or(false)
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !RESERVED_WORDS.contains(&name)
+}
+
+// Taken from: https://github.com/elm/compiler/blob/master/compiler/src/Parse/Variable.hs
+const RESERVED_WORDS: &[&str] = &[
+    "if", "then", "else", "case", "of", "let", "in", "type", "module", "where", "import",
+    "exposing", "as", "port",
+];
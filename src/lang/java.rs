@@ -0,0 +1,697 @@
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use crate::*;
+
+use super::variant_type_name;
+
+/// Allows you to control the behavior of the Java exporter
+#[derive(Default)]
+pub struct ExportConfiguration {
+    /// control how nullable types are exported
+    nullable: NullableBehavior,
+    /// override the Java type of well-known types
+    well_known_types: BTreeMap<WellKnownType, String>,
+}
+
+impl ExportConfiguration {
+    /// Construct a new `ExportConfiguration`
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configure how nullable types are exported
+    pub fn nullable(mut self, nullable: NullableBehavior) -> Self {
+        self.nullable = nullable;
+        self
+    }
+
+    /// Configure the Java type a [`WellKnownType`] is exported as. Eg. `String` instead of `OffsetDateTime`
+    /// By default dates and times use `java.time` which requires the [jackson-datatype-jsr310](https://github.com/FasterXML/jackson-modules-java8) module to be registered.
+    pub fn well_known_type(mut self, ty: WellKnownType, java: impl Into<String>) -> Self {
+        self.well_known_types.insert(ty, java.into());
+        self
+    }
+}
+
+/// Allows you to configure how Specta's Java exporter will deal with nullable types (`Option<T>`).
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullableBehavior {
+    /// Annotate the type with the [JSpecify](https://jspecify.dev) `@Nullable` annotation. Eg. `@Nullable String`
+    #[default]
+    Annotation,
+    /// Wrap the type in a `java.util.Optional`. Eg. `Optional<String>`
+    /// This requires the [jackson-datatype-jdk8](https://github.com/FasterXML/jackson-modules-java8) module to be registered.
+    Optional,
+}
+
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum JavaExportError {
+    #[error("Failed to export type '{}' on field `{}`: {err}", .ty_name.unwrap_or_default(), .field_name.unwrap_or_default())]
+    WithCtx {
+        ty_name: Option<&'static str>,
+        field_name: Option<&'static str>,
+        err: Box<JavaExportError>,
+    },
+    #[error("Cannot export anonymous object. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousObject,
+    #[error("Cannot export anonymous enum. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousEnum,
+    #[error("You have defined a type with the name '{0}' which is a reserved name by the Java exporter. Try renaming it or using `#[specta(rename = \"new name\")]`")]
+    ForbiddenTypeName(&'static str),
+    #[error("You have defined a field '{1}' on type '{0}' which is not a valid Java identifier. Try renaming it or using `#[specta(rename = \"new name\")]`")]
+    ForbiddenFieldName(String, &'static str),
+    #[error("Type cannot be exported: {0:?}")]
+    CannotExport(Box<DataTypeExt>),
+    #[error("Java does not support {0}")]
+    Unsupported(&'static str),
+    #[error("Cannot export type due to an internal error. This likely is a bug in Specta itself and not your code: {0}")]
+    InternalError(&'static str),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// The imports required by the declarations generated by this exporter.
+pub fn imports(conf: &ExportConfiguration) -> String {
+    let mut imports = vec![
+        "com.fasterxml.jackson.annotation.*",
+        "java.math.BigDecimal",
+        "java.math.BigInteger",
+        "java.time.*",
+        "java.util.List",
+        "java.util.Map",
+        "java.util.Set",
+        "java.util.UUID",
+    ];
+    imports.push(match conf.nullable {
+        NullableBehavior::Annotation => "org.jspecify.annotations.Nullable",
+        NullableBehavior::Optional => "java.util.Optional",
+    });
+
+    imports
+        .into_iter()
+        .map(|i| format!("import {i};\n"))
+        .collect()
+}
+
+/// The [Java](https://www.java.com) [`Language`](crate::Language).
+pub struct Java;
+
+impl Language for Java {
+    type Config = ExportConfiguration;
+    type Error = JavaExportError;
+
+    fn export_datatype(conf: &Self::Config, def: &DataTypeExt) -> Result<String, Self::Error> {
+        export_datatype(conf, def)
+    }
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to a Java declaration.
+/// Eg. `public record Foo(@JsonProperty("demo") String demo) {}`
+pub fn export<T: Type>(conf: &ExportConfiguration) -> Result<String, JavaExportError> {
+    export_datatype(
+        conf,
+        &T::definition(DefOpts {
+            parent_inline: true,
+            type_map: &mut TypeDefs::default(),
+        }),
+    )
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to a Java type.
+pub fn inline<T: Type>(conf: &ExportConfiguration) -> Result<String, JavaExportError> {
+    datatype(
+        conf,
+        &T::inline(
+            DefOpts {
+                parent_inline: true,
+                type_map: &mut TypeDefs::default(),
+            },
+            &[],
+        ),
+    )
+}
+
+/// Convert a DataType to a Java declaration.
+///
+/// Structs are exported as a `record`, enums containing only unit variants as an `enum`
+/// and all other enums as a `sealed interface` with a nested `record` for each variant.
+/// The Jackson annotations match the representation Serde uses for the type.
+pub fn export_datatype(
+    conf: &ExportConfiguration,
+    def: &DataTypeExt,
+) -> Result<String, JavaExportError> {
+    let (name, generics) = match &def.inner {
+        DataType::Object(ObjectType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(JavaExportError::AnonymousObject);
+            }
+
+            (*name, generics)
+        }
+        DataType::Enum(EnumType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(JavaExportError::AnonymousEnum);
+            }
+
+            (*name, generics)
+        }
+        DataType::Tuple(TupleType { name, generics, .. }) => (*name, generics),
+        _ => return Err(JavaExportError::CannotExport(Box::new(def.clone()))),
+    };
+
+    if !is_valid_ident(name) {
+        return Err(JavaExportError::ForbiddenTypeName(name));
+    }
+
+    let decl = export_declaration(conf, name, generics, &def.inner).map_err(|err| {
+        JavaExportError::WithCtx {
+            ty_name: Some(def.name),
+            field_name: None,
+            err: Box::new(err),
+        }
+    })?;
+
+    Ok(format!(
+        "{}{}{decl}",
+        javadoc(&docs(def.comments, def.deprecated)),
+        def.deprecated
+            .map(deprecated_annotation)
+            .unwrap_or_default()
+    ))
+}
+
+fn export_declaration(
+    conf: &ExportConfiguration,
+    name: &'static str,
+    generics: &[&'static str],
+    typ: &DataType,
+) -> Result<String, JavaExportError> {
+    let name_with_generics = format!("{name}{}", type_params(generics));
+
+    Ok(match typ {
+        DataType::Object(ObjectType { fields, tag, .. }) => {
+            let mut annotations = vec![];
+            if let Some((tag, value)) = tag {
+                annotations.push(format!("@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = \"{tag}\")"));
+                annotations.push(format!("@JsonTypeName(\"{value}\")"));
+            }
+
+            record(
+                &annotations,
+                "public ",
+                &name_with_generics,
+                &object_components(conf, name, fields)?,
+                None,
+                &[],
+            )
+        }
+        DataType::Tuple(TupleType { fields, .. }) => {
+            tuple_record(conf, "public ", name, &name_with_generics, fields, None)?
+        }
+        DataType::Enum(EnumType { variants, repr, .. }) => {
+            if variants.is_empty() {
+                return Err(JavaExportError::Unsupported("enums without any variants"));
+            }
+
+            let all_unit = variants.iter().all(|v| matches!(v, EnumVariant::Unit(..)));
+            if all_unit && matches!(repr, EnumRepr::External) {
+                let constants = variants
+                    .iter()
+                    .map(|v| {
+                        variant_ident(name, v.name())
+                            .map(|ident| format!("    @JsonProperty(\"{}\") {ident},\n", v.name()))
+                    })
+                    .collect::<Result<String, _>>()?;
+
+                return Ok(format!("public enum {name} {{\n{constants}}}"));
+            }
+
+            let type_info = match repr {
+                EnumRepr::External if variants.iter().any(|v| matches!(v, EnumVariant::Unit(..))) => {
+                    return Err(JavaExportError::Unsupported(
+                        "externally tagged enums which mix unit variants with data carrying variants",
+                    ))
+                }
+                EnumRepr::External => "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.WRAPPER_OBJECT)".to_string(),
+                EnumRepr::Internal { tag } | EnumRepr::Adjacent { tag, .. } => format!("@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = \"{tag}\")"),
+                EnumRepr::Untagged if variants.iter().all(|v| matches!(v, EnumVariant::Named(_))) => {
+                    "@JsonTypeInfo(use = JsonTypeInfo.Id.DEDUCTION)".to_string()
+                }
+                EnumRepr::Untagged => {
+                    return Err(JavaExportError::Unsupported(
+                        "untagged enums with variants that aren't structs",
+                    ))
+                }
+            };
+
+            let implements = format!("{name}{}", type_params(generics));
+            let mut subtypes = vec![];
+            let mut members = vec![];
+            for variant in variants {
+                let ident = variant_ident(name, variant.name())?;
+                let variant_name = format!("{ident}{}", type_params(generics));
+
+                let member = match (repr, variant) {
+                    (_, EnumVariant::Unit(..)) => {
+                        record(&[], "", &variant_name, &[], Some(&implements), &[])
+                    }
+                    (EnumRepr::Adjacent { content, .. }, variant) => {
+                        if !is_valid_ident(content) {
+                            return Err(JavaExportError::ForbiddenFieldName(
+                                name.to_owned(),
+                                content,
+                            ));
+                        }
+
+                        match variant {
+                            EnumVariant::Unnamed(TupleType { fields, .. }) if fields.len() == 1 => {
+                                record(
+                                    &[],
+                                    "",
+                                    &variant_name,
+                                    &[format!(
+                                        "@JsonProperty(\"{content}\") {} {content}",
+                                        datatype(conf, &fields[0])?
+                                    )],
+                                    Some(&implements),
+                                    &[],
+                                )
+                            }
+                            variant => {
+                                let body_name = format!("Body{}", type_params(generics));
+                                let body = match variant {
+                                    EnumVariant::Named(ObjectType { fields, .. }) => record(
+                                        &[],
+                                        "public ",
+                                        &body_name,
+                                        &object_components(conf, name, fields)?,
+                                        None,
+                                        &[],
+                                    ),
+                                    EnumVariant::Unnamed(TupleType { fields, .. }) => tuple_record(
+                                        conf, "public ", "Body", &body_name, fields, None,
+                                    )?,
+                                    EnumVariant::Unit(..) => unreachable!(),
+                                };
+
+                                record(
+                                    &[],
+                                    "",
+                                    &variant_name,
+                                    &[format!(
+                                        "@JsonProperty(\"{content}\") {body_name} {content}"
+                                    )],
+                                    Some(&implements),
+                                    &[body],
+                                )
+                            }
+                        }
+                    }
+                    (EnumRepr::Internal { .. }, EnumVariant::Unnamed(_)) => {
+                        return Err(JavaExportError::Unsupported(
+                            "tuple variants in internally tagged enums",
+                        ))
+                    }
+                    (_, EnumVariant::Unnamed(TupleType { fields, .. })) => {
+                        tuple_record(conf, "", &ident, &variant_name, fields, Some(&implements))?
+                    }
+                    (_, EnumVariant::Named(ObjectType { fields, .. })) => record(
+                        &[],
+                        "",
+                        &variant_name,
+                        &object_components(conf, name, fields)?,
+                        Some(&implements),
+                        &[],
+                    ),
+                };
+
+                subtypes.push(format!(
+                    "    @JsonSubTypes.Type(value = {name}.{ident}.class, name = \"{}\"),\n",
+                    variant.name()
+                ));
+                members.push(indent(&member));
+            }
+
+            format!(
+                "{type_info}\n@JsonSubTypes({{\n{}}})\npublic sealed interface {implements} {{\n{}\n}}",
+                subtypes.join(""),
+                members.join("\n\n")
+            )
+        }
+        _ => {
+            return Err(JavaExportError::InternalError(
+                "Attempted to export a type which is not a record or enum!",
+            ))
+        }
+    })
+}
+
+/// Convert a DataType to a Java type
+/// Eg. `List<String>`
+pub fn datatype(conf: &ExportConfiguration, typ: &DataType) -> Result<String, JavaExportError> {
+    Ok(match typ {
+        DataType::Any => "Object".into(),
+        primitive_def!(i8) => "Byte".into(),
+        primitive_def!(i16 u8) => "Short".into(),
+        primitive_def!(i32 u16) => "Integer".into(),
+        primitive_def!(i64 isize u32 usize) => "Long".into(),
+        primitive_def!(u64 i128 u128) => "BigInteger".into(),
+        primitive_def!(f32) => "Float".into(),
+        primitive_def!(f64) => "Double".into(),
+        primitive_def!(bool) => "Boolean".into(),
+        primitive_def!(char) => "Character".into(),
+        primitive_def!(String) => "String".into(),
+        DataType::Literal(_) => return Err(JavaExportError::Unsupported("literal types")),
+        DataType::WellKnown(ty) => match conf.well_known_types.get(ty) {
+            Some(java) => java.clone(),
+            None => match ty {
+                WellKnownType::DateTime => "OffsetDateTime",
+                WellKnownType::NaiveDateTime => "LocalDateTime",
+                WellKnownType::Date => "LocalDate",
+                WellKnownType::Time => "LocalTime",
+                WellKnownType::Uuid => "UUID",
+                WellKnownType::Decimal => "BigDecimal",
+                WellKnownType::Json => "Object",
+            }
+            .into(),
+        },
+        DataType::Nullable(def) => nullable(conf, datatype(conf, def)?),
+        DataType::Record(def) => format!(
+            "Map<{}, {}>",
+            datatype(conf, &def.0)?,
+            datatype(conf, &def.1)?
+        ),
+        DataType::List(def) => format!("List<{}>", datatype(conf, def)?),
+        DataType::Set(def) => format!("Set<{}>", datatype(conf, def)?),
+        DataType::Tuple(TupleType { fields, .. }) => match &fields[..] {
+            [] => "Void".into(),
+            [ty] => datatype(conf, ty)?,
+            _ => return Err(JavaExportError::Unsupported("inline tuples")),
+        },
+        DataType::Object(_) => return Err(JavaExportError::AnonymousObject),
+        DataType::Enum(_) => return Err(JavaExportError::AnonymousEnum),
+        DataType::Reference { name, generics, .. } => match &generics[..] {
+            [] => name.to_string(),
+            generics => format!(
+                "{name}<{}>",
+                generics
+                    .iter()
+                    .map(|g| datatype(conf, g))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+        },
+        DataType::Generic(GenericType(ident)) => ident.to_string(),
+        DataType::Placeholder => {
+            return Err(JavaExportError::InternalError(
+                "Attempted to export a placeholder!",
+            ))
+        }
+    })
+}
+
+fn nullable(conf: &ExportConfiguration, ty: String) -> String {
+    match conf.nullable {
+        NullableBehavior::Annotation if ty.starts_with("@Nullable ") => ty,
+        NullableBehavior::Annotation => format!("@Nullable {ty}"),
+        NullableBehavior::Optional => format!("Optional<{ty}>"),
+    }
+}
+
+fn object_components(
+    conf: &ExportConfiguration,
+    type_name: &str,
+    fields: &[ObjectField],
+) -> Result<Vec<String>, JavaExportError> {
+    fields
+        .iter()
+        .map(|field| {
+            if field.flatten {
+                return Err(JavaExportError::Unsupported("flattened fields"));
+            } else if !is_valid_ident(field.name) {
+                return Err(JavaExportError::ForbiddenFieldName(
+                    type_name.to_owned(),
+                    field.name,
+                ));
+            }
+
+            datatype(conf, &field.ty)
+                .map(|ty| match (field.optional, &field.ty) {
+                    (true, DataType::Nullable(_)) | (false, _) => ty,
+                    (true, _) => nullable(conf, ty),
+                })
+                .map(|ty| {
+                    format!(
+                        "{}{}@JsonProperty(\"{2}\") {ty} {2}",
+                        javadoc(&docs(field.comments, None)),
+                        field
+                            .deprecated
+                            .map(|d| deprecated_annotation(d).replace('\n', " "))
+                            .unwrap_or_default(),
+                        field.name
+                    )
+                })
+                .map_err(|err| JavaExportError::WithCtx {
+                    ty_name: None,
+                    field_name: Some(field.name),
+                    err: Box::new(err),
+                })
+        })
+        .collect()
+}
+
+/// Tuple structs with a single field are serialized as the value of that field and any others as a JSON array.
+fn tuple_record(
+    conf: &ExportConfiguration,
+    modifiers: &str,
+    name: &str,
+    name_with_generics: &str,
+    fields: &[DataType],
+    implements: Option<&str>,
+) -> Result<String, JavaExportError> {
+    Ok(match fields {
+        [] => record(&[], modifiers, name_with_generics, &[], implements, &[]),
+        [ty] => record(
+            &[],
+            modifiers,
+            name_with_generics,
+            &[format!("@JsonValue {} value", datatype(conf, ty)?)],
+            implements,
+            &[format!(
+                "@JsonCreator(mode = JsonCreator.Mode.DELEGATING)\npublic {name} {{\n}}"
+            )],
+        ),
+        fields => {
+            let names = (0..fields.len())
+                .map(|i| format!("\"_{i}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            record(
+                &[
+                    "@JsonFormat(shape = JsonFormat.Shape.ARRAY)".into(),
+                    format!("@JsonPropertyOrder({{{names}}})"),
+                ],
+                modifiers,
+                name_with_generics,
+                &fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, ty)| datatype(conf, ty).map(|ty| format!("{ty} _{i}")))
+                    .collect::<Result<Vec<_>, _>>()?,
+                implements,
+                &[],
+            )
+        }
+    })
+}
+
+fn record(
+    annotations: &[String],
+    modifiers: &str,
+    name_with_generics: &str,
+    components: &[String],
+    implements: Option<&str>,
+    body: &[String],
+) -> String {
+    let mut result = annotations
+        .iter()
+        .map(|a| format!("{a}\n"))
+        .collect::<String>();
+
+    result.push_str(&format!("{modifiers}record {name_with_generics}("));
+    if !components.is_empty() {
+        result.push_str(&format!(
+            "\n{}\n",
+            components
+                .iter()
+                .map(|c| indent(c))
+                .collect::<Vec<_>>()
+                .join(",\n")
+        ));
+    }
+    result.push(')');
+
+    if let Some(implements) = implements {
+        result.push_str(&format!(" implements {implements}"));
+    }
+
+    result.push_str(" {\n");
+    result.push_str(
+        &body
+            .iter()
+            .map(|decl| indent(decl))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    );
+    if !body.is_empty() {
+        result.push('\n');
+    }
+    result.push('}');
+    result
+}
+
+fn type_params(generics: &[&'static str]) -> String {
+    match generics {
+        [] => "".into(),
+        generics => format!("<{}>", generics.join(", ")),
+    }
+}
+
+/// The `@Deprecated` annotation followed by a newline.
+fn deprecated_annotation(deprecated: Deprecated) -> String {
+    match deprecated.since {
+        Some(since) => format!("@Deprecated(since = \"{since}\")\n"),
+        None => "@Deprecated\n".into(),
+    }
+}
+
+/// The lines of the Javadoc for the doc comments and deprecation of a type or field.
+fn docs(comments: &[&str], deprecated: Option<Deprecated>) -> Vec<String> {
+    comments
+        .iter()
+        .map(|c| c.to_string())
+        .chain(deprecated.map(|d| {
+            ["@deprecated"]
+                .into_iter()
+                .chain(d.note)
+                .collect::<Vec<_>>()
+                .join(" ")
+        }))
+        .collect()
+}
+
+fn javadoc(lines: &[String]) -> String {
+    if lines.is_empty() {
+        return "".into();
+    }
+
+    let mut result = "/**\n".to_owned();
+    for line in lines {
+        result.push_str(&format!(" * {line}\n"));
+    }
+    result.push_str(" */\n");
+    result
+}
+
+fn indent(s: &str) -> String {
+    s.lines()
+        .map(|line| match line {
+            "" => "".into(),
+            line => format!("    {line}"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn variant_ident(
+    type_name: &'static str,
+    variant_name: &'static str,
+) -> Result<String, JavaExportError> {
+    let ident = variant_type_name("", variant_name);
+    match is_valid_ident(&ident) && ident != type_name {
+        true => Ok(ident),
+        false => Err(JavaExportError::ForbiddenFieldName(
+            type_name.to_owned(),
+            variant_name,
+        )),
+    }
+}
+
+fn is_valid_ident(ident: &str) -> bool {
+    ident
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        && ident
+            .chars()
+            .next()
+            .map(|first| !first.is_numeric())
+            .unwrap_or(false)
+        && ident != "_"
+        && !RESERVED_WORDS.contains(&ident)
+}
+
+// Taken from: https://docs.oracle.com/javase/specs/jls/se17/html/jls-3.html#jls-3.9
+const RESERVED_WORDS: &[&str] = &[
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "try",
+    "void",
+    "volatile",
+    "while",
+    "true",
+    "false",
+    "null",
+    "record",
+    "sealed",
+    "permits",
+    "var",
+    "yield",
+];
@@ -0,0 +1,209 @@
+use crate::{
+    ts::{self, ExportConfiguration, TsExportError},
+    *,
+};
+
+/// The [JSDoc](https://jsdoc.app) [`Language`](crate::Language).
+pub struct JsDoc;
+
+impl Language for JsDoc {
+    type Config = ExportConfiguration;
+    type Error = TsExportError;
+
+    fn export_datatype(conf: &Self::Config, def: &DataTypeExt) -> Result<String, Self::Error> {
+        export_datatype(conf, def)
+    }
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to a JSDoc `@typedef` comment block.
+/// Eg.
+/// ```text
+/// /**
+///  * @typedef {Object} Foo
+///  * @property {string} demo
+///  */
+/// ```
+pub fn export<T: Type>(conf: &ExportConfiguration) -> Result<String, TsExportError> {
+    export_datatype(
+        conf,
+        &T::definition(DefOpts {
+            parent_inline: true,
+            type_map: &mut TypeDefs::default(),
+        }),
+    )
+}
+
+/// Convert a DataType to a JSDoc `@typedef` comment block.
+///
+/// Objects are exported as `@typedef {Object}` with a `@property` for each field.
+/// Every other type is exported as a typedef of its inline Typescript representation,
+/// which Typescript understands when type checking Javascript with `// @ts-check`.
+pub fn export_datatype(
+    conf: &ExportConfiguration,
+    def: &DataTypeExt,
+) -> Result<String, TsExportError> {
+    let (name, generics) = match &def.inner {
+        DataType::Object(ObjectType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(TsExportError::AnonymousObject);
+            }
+
+            (name, generics)
+        }
+        DataType::Enum(EnumType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(TsExportError::AnonymousEnum);
+            }
+
+            (name, generics)
+        }
+        DataType::Tuple(TupleType { name, generics, .. }) => (name, generics),
+        _ => return Err(TsExportError::CannotExport(Box::new(def.clone()))),
+    };
+
+    if let Some(name) = ts::RESERVED_WORDS.iter().find(|v| *v == name) {
+        return Err(TsExportError::ForbiddenTypeName(name));
+    }
+
+    let mut lines = comment_lines(
+        conf,
+        &ts::CommentContext {
+            kind: ts::CommentKind::Type,
+            name: def.name,
+            comments: def.comments,
+            doc_tags: def.doc_tags,
+            deprecated: def.deprecated,
+            constraints: Default::default(),
+            depth: 0,
+        },
+    );
+    lines.extend(generics.iter().map(|g| format!("@template {g}")));
+
+    lines.extend(
+        typedef(conf, name, &def.inner).map_err(|err| TsExportError::WithCtx {
+            ty_name: Some(def.name),
+            field_name: None,
+            err: Box::new(err),
+        })?,
+    );
+
+    let mut result = "/**\n".to_owned();
+    for line in lines {
+        result.push_str(&format!(" * {line}\n"));
+    }
+    result.push_str(" */");
+    Ok(result)
+}
+
+fn typedef(
+    conf: &ExportConfiguration,
+    name: &str,
+    typ: &DataType,
+) -> Result<Vec<String>, TsExportError> {
+    match typ {
+        // `@property` can't express intersections or quoted keys so those objects fallback to their Typescript representation.
+        DataType::Object(ObjectType { fields, tag, .. })
+            if !fields.is_empty()
+                && fields
+                    .iter()
+                    .all(|f| !f.flatten && ts::is_valid_ident(f.name)) =>
+        {
+            let mut lines = vec![format!("@typedef {{Object}} {name}")];
+
+            for field in fields {
+                lines.push(property(conf, name, field)?);
+            }
+
+            if let Some((tag, value)) = tag {
+                lines.push(format!("@property {{\"{value}\"}} {tag}"));
+            }
+
+            Ok(lines)
+        }
+        typ => Ok(vec![format!(
+            "@typedef {{{}}} {name}",
+            ts::datatype(conf, typ)?
+        )]),
+    }
+}
+
+/// convert an object field into a JSDoc `@property` tag.
+/// Optional fields will be wrapped in square brackets. Eg. `@property {string | null} [name]`
+/// The doc comments on the field are joined into the description of the property when comments are enabled and are followed by the field's constraints.
+pub fn property(
+    conf: &ExportConfiguration,
+    type_name: &str,
+    field: &ObjectField,
+) -> Result<String, TsExportError> {
+    let field_name_safe = ts::sanitise_name(type_name, field.name)?;
+
+    let typ = ts::datatype(conf, &field.ty)
+        .map(|v| match (field.optional, &field.ty) {
+            (true, DataType::Nullable(_)) | (false, _) => v,
+            (true, _) => format!("{v} | null"),
+        })
+        .map_err(|err| TsExportError::WithCtx {
+            ty_name: None,
+            field_name: Some(field.name),
+            err: Box::new(err),
+        })?;
+
+    // Tags can't be nested within `@property` so only the doc comments are given to the formatter
+    let comments = comment_lines(
+        conf,
+        &ts::CommentContext {
+            kind: ts::CommentKind::Field,
+            name: field.name,
+            comments: field.comments,
+            doc_tags: &[],
+            deprecated: None,
+            constraints: Default::default(),
+            depth: 1,
+        },
+    )
+    .iter()
+    .map(|line| line.trim())
+    .filter(|line| !line.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+    // Tags can't be nested within `@property` so the constraints are described in words. Eg. `(minLength 1, maxLength 64)`
+    let constraints = field
+        .constraints
+        .to_lines()
+        .iter()
+        .map(|line| line.trim_start_matches('@').to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let description = match (comments.is_empty(), constraints.is_empty()) {
+        (true, true) => "".into(),
+        (false, true) => format!(" - {comments}"),
+        (true, false) => format!(" - ({constraints})"),
+        (false, false) => format!(" - {comments} ({constraints})"),
+    };
+
+    Ok(match field.optional {
+        true => format!("@property {{{typ}}} [{field_name_safe}]{description}"),
+        false => format!("@property {{{typ}}} {field_name_safe}{description}"),
+    })
+}
+
+/// Format the comment with the configured [`CommentFormatter`](ts::CommentFormatter) and unwrap it into lines which can be placed within a JSDoc block.
+/// Eg. `/**\n *  Foo\n */\n` and `// Foo\n` both become `[" Foo"]`.
+fn comment_lines(conf: &ExportConfiguration, ctx: &ts::CommentContext) -> Vec<String> {
+    let Some(formatter) = &conf.comment_exporter else {
+        return vec![];
+    };
+
+    formatter
+        .format(ctx)
+        .lines()
+        .filter(|line| !matches!(line.trim(), "/**" | "*/"))
+        .map(|line| {
+            line.strip_prefix(" * ")
+                .or_else(|| line.strip_prefix(" *"))
+                .or_else(|| line.strip_prefix("//"))
+                .unwrap_or(line)
+                .to_string()
+        })
+        .collect()
+}
@@ -8,6 +8,26 @@
 #[cfg(feature = "typescript")]
 pub mod ts;
 
+/// [JSDoc](https://jsdoc.app) support for plain Javascript consumers.
+#[cfg(feature = "jsdoc")]
+pub mod jsdoc;
+
+/// [Elm](https://elm-lang.org) support.
+#[cfg(feature = "elm")]
+pub mod elm;
+
+/// [C](https://en.wikipedia.org/wiki/C_(programming_language)) header support for types shared across an FFI boundary.
+#[cfg(feature = "c")]
+pub mod c;
+
+/// [PHP](https://www.php.net) support.
+#[cfg(feature = "php")]
+pub mod php;
+
+/// [Java](https://www.java.com) support using [Jackson](https://github.com/FasterXML/jackson) annotations.
+#[cfg(feature = "java")]
+pub mod java;
+
 // /// [Rust](https://www.rust-lang.org) support.
 // #[cfg(feature = "rust")]
 // pub mod rust;
@@ -24,6 +44,58 @@ pub mod ts;
 // #[cfg(feature = "go")]
 // pub mod go;
 
+use crate::{DataTypeExt, DefOpts, Type, TypeDefs};
+
+/// A language which Specta can export types into.
+///
+/// This is implemented by every built-in exporter (Eg. [`ts::Typescript`](crate::ts::Typescript)) so they can be driven generically
+/// and can be implemented by other crates to add support for new languages.
+pub trait Language {
+    /// The configuration accepted by the exporter. Use `()` if the exporter can't be configured.
+    type Config;
+    /// The error returned when a type can't be exported.
+    type Error: std::error::Error + From<std::io::Error>;
+
+    /// The text at the start of every file exported with [`Export`](crate::export::Export) unless [`Output::header`](crate::export::Output::header) is configured.
+    const HEADER: Option<&'static str> = None;
+
+    /// The header for the provided configuration. Override this if the header depends on the configuration.
+    fn header(_conf: &Self::Config) -> Option<String> {
+        Self::HEADER.map(Into::into)
+    }
+
+    /// Convert a DataType to a declaration in this language.
+    fn export_datatype(conf: &Self::Config, def: &DataTypeExt) -> Result<String, Self::Error>;
+
+    /// Convert a type which implements [`Type`](crate::Type) to a declaration in this language.
+    fn export_type<T: Type>(conf: &Self::Config) -> Result<String, Self::Error> {
+        Self::export_datatype(
+            conf,
+            &T::definition(DefOpts {
+                parent_inline: true,
+                type_map: &mut TypeDefs::default(),
+            }),
+        )
+    }
+
+    /// Convert every type in the [`TypeDefs`] to a declaration in this language. Each declaration is separated by a blank line.
+    fn export_all(conf: &Self::Config, types: &TypeDefs) -> Result<String, Self::Error> {
+        Self::export_many(conf, types.values())
+    }
+
+    /// Convert the types to declarations in this language, keeping the order they are provided in. Each declaration is separated by a blank line.
+    fn export_many<'a>(
+        conf: &Self::Config,
+        types: impl IntoIterator<Item = &'a DataTypeExt>,
+    ) -> Result<String, Self::Error> {
+        Ok(types
+            .into_iter()
+            .map(|def| Self::export_datatype(conf, def))
+            .collect::<Result<Vec<_>, _>>()?
+            .join("\n\n"))
+    }
+}
+
 macro_rules! primitive_def {
     ($($t:ident)+) => {
         $(DataType::Primitive(PrimitiveType::$t))|+
@@ -31,3 +103,22 @@ macro_rules! primitive_def {
 }
 
 pub(crate) use primitive_def;
+
+/// Join the name of an enum and one of its variants into a single type name. Eg. `Shape` and `circle` becomes `ShapeCircle`.
+/// This is used by languages where variants are declared as standalone types or constructors which share a namespace.
+#[cfg(any(feature = "elm", feature = "php", feature = "java"))]
+pub(crate) fn variant_type_name(type_name: &str, variant_name: &str) -> String {
+    let mut result = type_name.to_string();
+    let mut capitalize = true;
+    for c in variant_name.chars() {
+        if !c.is_alphanumeric() {
+            capitalize = true;
+        } else if capitalize {
+            result.extend(c.to_uppercase());
+            capitalize = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
@@ -0,0 +1,629 @@
+use thiserror::Error;
+
+use crate::*;
+
+use super::variant_type_name;
+
+/// Allows you to control the behavior of the PHP exporter
+#[derive(Default)]
+pub struct ExportConfiguration {
+    /// control how bigint types are exported
+    bigint: BigIntExportBehavior,
+}
+
+impl ExportConfiguration {
+    /// Construct a new `ExportConfiguration`
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configure the BigInt handling behaviour
+    pub fn bigint(mut self, bigint: BigIntExportBehavior) -> Self {
+        self.bigint = bigint;
+        self
+    }
+}
+
+/// Allows you to configure how Specta's PHP exporter will deal with BigInt types (u64 usize i128 u128).
+/// PHP's `int` is a signed 64 bit integer so it can't hold the values of these types above `i64::MAX`.
+#[derive(Default)]
+pub enum BigIntExportBehavior {
+    /// Export BigInt as a PHP `string`
+    /// WARNING: Specta takes no responsibility that the Rust number is encoded as a string.
+    /// Make sure you instruct serde <https://github.com/serde-rs/json/issues/329#issuecomment-305608405> or your other serializer of this.
+    String,
+    /// Export `u64` and `usize` as a PHP `int`. `i128` and `u128` still can't be exported.
+    /// WARNING: Values above `i64::MAX` will be decoded as a `float` and lose precision.
+    Int,
+    /// Abort the export with an error
+    /// This is the default behavior because without integration from your serializer we can't guarantee data loss won't occur.
+    #[default]
+    Fail,
+}
+
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum PhpExportError {
+    #[error("Failed to export type '{}' on field `{}`: {err}", .ty_name.unwrap_or_default(), .field_name.unwrap_or_default())]
+    WithCtx {
+        ty_name: Option<&'static str>,
+        field_name: Option<&'static str>,
+        err: Box<PhpExportError>,
+    },
+    #[error("Cannot export anonymous object. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousObject,
+    #[error("Cannot export anonymous enum. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousEnum,
+    #[error("You have defined a type with the name '{0}' which is a reserved name by the PHP exporter. Try renaming it or using `#[specta(rename = \"new name\")]`")]
+    ForbiddenTypeName(&'static str),
+    #[error("You have defined a field '{1}' on type '{0}' which is not a valid PHP property name. Try renaming it or using `#[specta(rename = \"new name\")]`")]
+    ForbiddenFieldName(String, &'static str),
+    #[error("Type cannot be exported: {0:?}")]
+    CannotExport(Box<DataTypeExt>),
+    #[error("Your Specta configuration forbids exporting BigInt types (u64, usize, i128, u128) because PHP's `int` can't represent them. You can change this behavior by editing your `ExportConfiguration`")]
+    BigIntForbidden,
+    #[error("PHP does not support {0}")]
+    Unsupported(&'static str),
+    #[error("Cannot export type due to an internal error. This likely is a bug in Specta itself and not your code: {0}")]
+    InternalError(&'static str),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// A PHP type split into the native type declaration and the richer type used in PHPStan/Psalm docblocks.
+/// Eg. `array` and `list<string>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhpType {
+    /// The type used in the property declaration. Eg. `array`
+    pub native: String,
+    /// The type used in the `@param` docblock. Eg. `list<string>`
+    pub doc: String,
+}
+
+impl PhpType {
+    fn same(ty: &str) -> Self {
+        Self {
+            native: ty.into(),
+            doc: ty.into(),
+        }
+    }
+}
+
+/// The [PHP](https://www.php.net) [`Language`](crate::Language).
+pub struct Php;
+
+impl Language for Php {
+    type Config = ExportConfiguration;
+    type Error = PhpExportError;
+
+    const HEADER: Option<&'static str> = Some("<?php");
+
+    fn export_datatype(conf: &Self::Config, def: &DataTypeExt) -> Result<String, Self::Error> {
+        export_datatype(conf, def)
+    }
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to a PHP declaration.
+/// Eg. `final readonly class Foo { public function __construct(public string $demo) {} }`
+pub fn export<T: Type>(conf: &ExportConfiguration) -> Result<String, PhpExportError> {
+    export_datatype(
+        conf,
+        &T::definition(DefOpts {
+            parent_inline: true,
+            type_map: &mut TypeDefs::default(),
+        }),
+    )
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to a PHP type.
+pub fn inline<T: Type>(conf: &ExportConfiguration) -> Result<PhpType, PhpExportError> {
+    datatype(
+        conf,
+        &T::inline(
+            DefOpts {
+                parent_inline: true,
+                type_map: &mut TypeDefs::default(),
+            },
+            &[],
+        ),
+    )
+}
+
+/// Convert a DataType to a PHP declaration.
+///
+/// Structs are exported as a `final readonly class` with promoted constructor properties,
+/// enums containing only unit variants as a string backed `enum`
+/// and all other enums as an `interface` implemented by a class for each variant.
+pub fn export_datatype(
+    conf: &ExportConfiguration,
+    def: &DataTypeExt,
+) -> Result<String, PhpExportError> {
+    let (name, generics) = match &def.inner {
+        DataType::Object(ObjectType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(PhpExportError::AnonymousObject);
+            }
+
+            (*name, generics)
+        }
+        DataType::Enum(EnumType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(PhpExportError::AnonymousEnum);
+            }
+
+            (*name, generics)
+        }
+        DataType::Tuple(TupleType { name, generics, .. }) => (*name, generics),
+        _ => return Err(PhpExportError::CannotExport(Box::new(def.clone()))),
+    };
+
+    if !is_valid_ident(name) || RESERVED_WORDS.contains(&name.to_lowercase().as_str()) {
+        return Err(PhpExportError::ForbiddenTypeName(name));
+    }
+
+    let mut docs = comment_lines(def.comments, def.doc_tags, def.deprecated);
+    docs.extend(generics.iter().map(|g| format!("@template {g}")));
+
+    export_declaration(conf, name, generics, &docs, &def.inner).map_err(|err| {
+        PhpExportError::WithCtx {
+            ty_name: Some(def.name),
+            field_name: None,
+            err: Box::new(err),
+        }
+    })
+}
+
+fn export_declaration(
+    conf: &ExportConfiguration,
+    name: &'static str,
+    generics: &[&'static str],
+    docs: &[String],
+    typ: &DataType,
+) -> Result<String, PhpExportError> {
+    Ok(match typ {
+        DataType::Object(ObjectType { fields, tag, .. }) => {
+            let mut properties = object_properties(conf, name, fields)?;
+            if let Some((tag, value)) = tag {
+                properties.push(Property {
+                    name: tag.to_string(),
+                    ty: PhpType::same("string"),
+                    default: Some(format!("'{value}'")),
+                    comments: &[],
+                    doc_tags: &[],
+                    deprecated: None,
+                });
+            }
+
+            class(name, docs, None, &properties)
+        }
+        DataType::Tuple(TupleType { fields, .. }) => {
+            class(name, docs, None, &tuple_properties(conf, fields)?)
+        }
+        DataType::Enum(EnumType { variants, .. }) => match &variants[..] {
+            [] => return Err(PhpExportError::Unsupported("enums without any variants")),
+            variants if variants.iter().all(|v| matches!(v, EnumVariant::Unit(..))) => {
+                let cases = variants
+                    .iter()
+                    .map(|v| {
+                        let case = variant_type_name("", v.name());
+                        match is_valid_ident(&case) {
+                            true => Ok(format!(
+                                "{}    case {case} = '{}';\n",
+                                docblock(&comment_lines(v.comments(), &[], v.deprecated()), "    "),
+                                v.name()
+                            )),
+                            false => Err(PhpExportError::ForbiddenFieldName(
+                                name.to_owned(),
+                                v.name(),
+                            )),
+                        }
+                    })
+                    .collect::<Result<String, _>>()?;
+
+                format!("{}enum {name}: string\n{{\n{cases}}}", docblock(docs, ""))
+            }
+            variants => {
+                let mut decls = vec![format!("{}interface {name}\n{{\n}}", docblock(docs, ""))];
+
+                // Each variant carries the type parameters of the enum so it can implement the generic interface.
+                let implements = match generics.len() {
+                    0 => name.to_string(),
+                    _ => format!("{name}<{}>", generics.join(", ")),
+                };
+                let mut variant_docs = generics
+                    .iter()
+                    .map(|g| format!("@template {g}"))
+                    .collect::<Vec<_>>();
+                if !generics.is_empty() {
+                    variant_docs.push(format!("@implements {implements}"));
+                }
+
+                for variant in variants {
+                    let properties = match variant {
+                        EnumVariant::Unit(..) => vec![],
+                        EnumVariant::Unnamed(TupleType { fields, .. }) => {
+                            tuple_properties(conf, fields)?
+                        }
+                        EnumVariant::Named(ObjectType { fields, .. }) => {
+                            object_properties(conf, name, fields)?
+                        }
+                    };
+
+                    let mut docs = comment_lines(variant.comments(), &[], variant.deprecated());
+                    docs.extend(variant_docs.iter().cloned());
+
+                    decls.push(class(
+                        &variant_type_name(name, variant.name()),
+                        &docs,
+                        Some(name),
+                        &properties,
+                    ));
+                }
+
+                decls.join("\n\n")
+            }
+        },
+        _ => {
+            return Err(PhpExportError::InternalError(
+                "Attempted to export a type which is not a class or enum!",
+            ))
+        }
+    })
+}
+
+/// Convert a DataType to a PHP type
+/// Eg. `array` with the docblock type `list<string>`
+pub fn datatype(conf: &ExportConfiguration, typ: &DataType) -> Result<PhpType, PhpExportError> {
+    Ok(match typ {
+        DataType::Any => PhpType::same("mixed"),
+        primitive_def!(i8 i16 i32 i64 isize u8 u16 u32) => PhpType::same("int"),
+        primitive_def!(f32 f64) => PhpType::same("float"),
+        primitive_def!(u64 usize i128 u128) => match conf.bigint {
+            BigIntExportBehavior::String => PhpType::same("string"),
+            BigIntExportBehavior::Int if matches!(typ, primitive_def!(u64 usize)) => {
+                PhpType::same("int")
+            }
+            BigIntExportBehavior::Int => {
+                return Err(PhpExportError::Unsupported("128 bit numbers"))
+            }
+            BigIntExportBehavior::Fail => return Err(PhpExportError::BigIntForbidden),
+        },
+        primitive_def!(String char) => PhpType::same("string"),
+        primitive_def!(bool) => PhpType::same("bool"),
+        DataType::Literal(_) => return Err(PhpExportError::Unsupported("literal types")),
+        DataType::WellKnown(ty) => datatype(conf, &ty.repr())?,
+        DataType::Nullable(def) => {
+            let ty = datatype(conf, def)?;
+            PhpType {
+                native: match ty.native.as_str() {
+                    "mixed" => ty.native,
+                    native => format!("?{native}"),
+                },
+                doc: match ty.doc.as_str() {
+                    "mixed" => ty.doc,
+                    doc => format!("{doc}|null"),
+                },
+            }
+        }
+        DataType::Record(def) => PhpType {
+            native: "array".into(),
+            doc: format!(
+                "array<{}, {}>",
+                datatype(conf, &def.0)?.doc,
+                datatype(conf, &def.1)?.doc
+            ),
+        },
+        DataType::List(def) | DataType::Set(def) => PhpType {
+            native: "array".into(),
+            doc: format!("list<{}>", datatype(conf, def)?.doc),
+        },
+        DataType::Tuple(TupleType { fields, .. }) => match &fields[..] {
+            [] => PhpType::same("null"),
+            [ty] => datatype(conf, ty)?,
+            fields => PhpType {
+                native: "array".into(),
+                doc: format!(
+                    "array{{{}}}",
+                    fields
+                        .iter()
+                        .map(|ty| datatype(conf, ty).map(|ty| ty.doc))
+                        .collect::<Result<Vec<_>, _>>()?
+                        .join(", ")
+                ),
+            },
+        },
+        DataType::Object(ObjectType { name, fields, .. }) => match &fields[..] {
+            [] => PhpType::same("null"),
+            fields => PhpType {
+                native: "array".into(),
+                doc: format!(
+                    "array{{{}}}",
+                    object_properties(conf, name, fields)?
+                        .into_iter()
+                        .zip(fields)
+                        .map(|(prop, field)| match field.optional {
+                            true => format!("{}?: {}", prop.name, prop.ty.doc),
+                            false => format!("{}: {}", prop.name, prop.ty.doc),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+        },
+        DataType::Enum(_) => return Err(PhpExportError::Unsupported("inline enums")),
+        DataType::Reference { name, generics, .. } => PhpType {
+            native: name.to_string(),
+            doc: match &generics[..] {
+                [] => name.to_string(),
+                generics => format!(
+                    "{name}<{}>",
+                    generics
+                        .iter()
+                        .map(|g| datatype(conf, g).map(|ty| ty.doc))
+                        .collect::<Result<Vec<_>, _>>()?
+                        .join(", ")
+                ),
+            },
+        },
+        DataType::Generic(GenericType(ident)) => PhpType {
+            native: "mixed".into(),
+            doc: ident.to_string(),
+        },
+        DataType::Placeholder => {
+            return Err(PhpExportError::InternalError(
+                "Attempted to export a placeholder!",
+            ))
+        }
+    })
+}
+
+struct Property {
+    name: String,
+    ty: PhpType,
+    default: Option<String>,
+    comments: &'static [&'static str],
+    doc_tags: &'static [DocTag],
+    deprecated: Option<Deprecated>,
+}
+
+fn object_properties(
+    conf: &ExportConfiguration,
+    type_name: &str,
+    fields: &[ObjectField],
+) -> Result<Vec<Property>, PhpExportError> {
+    let mut properties = fields
+        .iter()
+        .map(|field| {
+            if field.flatten {
+                return Err(PhpExportError::Unsupported("flattened fields"));
+            } else if !is_valid_ident(field.name) || field.name == "this" {
+                return Err(PhpExportError::ForbiddenFieldName(
+                    type_name.to_owned(),
+                    field.name,
+                ));
+            }
+
+            let ty = match (field.optional, &field.ty) {
+                (true, DataType::Nullable(_)) | (false, _) => datatype(conf, &field.ty),
+                (true, ty) => datatype(conf, &DataType::Nullable(Box::new(ty.clone()))),
+            }
+            .map_err(|err| PhpExportError::WithCtx {
+                ty_name: None,
+                field_name: Some(field.name),
+                err: Box::new(err),
+            })?;
+
+            Ok(Property {
+                name: field.name.to_string(),
+                ty,
+                default: None,
+                comments: field.comments,
+                doc_tags: field.doc_tags,
+                deprecated: field.deprecated,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // PHP only allows parameters with a default at the end of the parameter list
+    for (property, field) in properties.iter_mut().zip(fields).rev() {
+        if !field.optional {
+            break;
+        }
+
+        property.default = Some("null".into());
+    }
+
+    Ok(properties)
+}
+
+fn tuple_properties(
+    conf: &ExportConfiguration,
+    fields: &[DataType],
+) -> Result<Vec<Property>, PhpExportError> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            Ok(Property {
+                name: match fields.len() {
+                    1 => "value".into(),
+                    _ => format!("_{i}"),
+                },
+                ty: datatype(conf, ty)?,
+                default: None,
+                comments: &[],
+                doc_tags: &[],
+                deprecated: None,
+            })
+        })
+        .collect()
+}
+
+fn class(name: &str, docs: &[String], implements: Option<&str>, properties: &[Property]) -> String {
+    let implements = implements
+        .map(|i| format!(" implements {i}"))
+        .unwrap_or_default();
+
+    let params = properties
+        .iter()
+        .filter(|p| p.ty.native != p.ty.doc)
+        .map(|p| format!("@param {} ${}", p.ty.doc, p.name))
+        .collect::<Vec<_>>();
+
+    let constructor = match properties {
+        [] => "".into(),
+        properties => format!(
+            "{}    public function __construct(\n{}    ) {{\n    }}\n",
+            docblock(&params, "    "),
+            properties
+                .iter()
+                .map(|p| {
+                    let docs = docblock(
+                        &comment_lines(p.comments, p.doc_tags, p.deprecated),
+                        "        ",
+                    );
+                    match &p.default {
+                        Some(default) => format!(
+                            "{docs}        public {} ${} = {default},\n",
+                            p.ty.native, p.name
+                        ),
+                        None => format!("{docs}        public {} ${},\n", p.ty.native, p.name),
+                    }
+                })
+                .collect::<String>()
+        ),
+    };
+
+    format!(
+        "{}final readonly class {name}{implements}\n{{\n{constructor}}}",
+        docblock(docs, "")
+    )
+}
+
+fn comment_lines(
+    comments: &[&str],
+    doc_tags: &[DocTag],
+    deprecated: Option<Deprecated>,
+) -> Vec<String> {
+    let mut lines = comments.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+    lines.extend(doc_tags.iter().flat_map(DocTag::to_lines));
+    // phpDocumentor's format is `@deprecated [<version>] [<description>]`
+    if let Some(deprecated) = deprecated {
+        lines.push(
+            ["@deprecated"]
+                .into_iter()
+                .chain(deprecated.since)
+                .chain(deprecated.note)
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+    }
+    lines
+}
+
+fn docblock(lines: &[String], indent: &str) -> String {
+    if lines.is_empty() {
+        return "".into();
+    }
+
+    let mut result = format!("{indent}/**\n");
+    for line in lines {
+        result.push_str(&format!("{indent} * {line}\n"));
+    }
+    result.push_str(&format!("{indent} */\n"));
+    result
+}
+
+fn is_valid_ident(ident: &str) -> bool {
+    ident.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && ident
+            .chars()
+            .next()
+            .map(|first| !first.is_numeric())
+            .unwrap_or(false)
+}
+
+// Taken from: https://www.php.net/manual/en/reserved.keywords.php and https://www.php.net/manual/en/reserved.other-reserved-words.php
+const RESERVED_WORDS: &[&str] = &[
+    "abstract",
+    "and",
+    "array",
+    "as",
+    "bool",
+    "break",
+    "callable",
+    "case",
+    "catch",
+    "class",
+    "clone",
+    "const",
+    "continue",
+    "declare",
+    "default",
+    "do",
+    "echo",
+    "else",
+    "elseif",
+    "empty",
+    "enddeclare",
+    "endfor",
+    "endforeach",
+    "endif",
+    "endswitch",
+    "endwhile",
+    "enum",
+    "eval",
+    "exit",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "fn",
+    "for",
+    "foreach",
+    "function",
+    "global",
+    "goto",
+    "if",
+    "implements",
+    "include",
+    "instanceof",
+    "insteadof",
+    "int",
+    "interface",
+    "isset",
+    "iterable",
+    "list",
+    "match",
+    "mixed",
+    "namespace",
+    "never",
+    "new",
+    "null",
+    "object",
+    "or",
+    "parent",
+    "print",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "require",
+    "return",
+    "self",
+    "static",
+    "string",
+    "switch",
+    "throw",
+    "trait",
+    "true",
+    "try",
+    "unset",
+    "use",
+    "var",
+    "void",
+    "while",
+    "xor",
+    "yield",
+];
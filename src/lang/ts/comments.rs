@@ -0,0 +1,93 @@
+//! Built in formatters for exporting Rust doc comments into Typescript.
+
+use crate::{Constraints, Deprecated, DocTag};
+
+/// What is being documented by a comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentKind {
+    /// A type declaration. Eg. `export type Foo = ...`
+    Type,
+    /// A field of an object.
+    Field,
+    /// A variant of an enum which is exported as a member of a union.
+    Variant,
+}
+
+/// Everything known about the item being documented which is given to a [`CommentFormatter`].
+#[derive(Debug, Clone)]
+pub struct CommentContext<'a> {
+    /// What is being documented.
+    pub kind: CommentKind,
+    /// The name of the type, field or variant.
+    pub name: &'a str,
+    /// Each line of the Rust doc comments. The leading space from `/// ` is kept.
+    pub comments: &'a [&'a str],
+    /// The documentation tags from `#[specta(example = "...")]`, `#[specta(since = "...")]` or `#[specta(doc_tag(...))]`.
+    pub doc_tags: &'a [DocTag],
+    /// The deprecation from Rust's `#[deprecated]` attribute.
+    pub deprecated: Option<Deprecated>,
+    /// The validation constraints on a field. Eg. `#[specta(max_length = 64)]`
+    pub constraints: Constraints,
+    /// How deeply the item is nested. Types are at depth `0` and their fields and variants are at depth `1`.
+    /// The exporter indents the returned comment so formatters don't need to indent it themselves.
+    pub depth: usize,
+}
+
+impl CommentContext<'_> {
+    /// The doc comments followed by the documentation tags, the constraints and the `@deprecated` tag in JSDoc syntax.
+    pub fn lines(&self) -> Vec<String> {
+        self.comments
+            .iter()
+            .map(|c| c.to_string())
+            .chain(self.doc_tags.iter().flat_map(DocTag::to_lines))
+            .chain(self.constraints.to_lines())
+            .chain(self.deprecated.map(deprecated_tag))
+            .collect()
+    }
+}
+
+/// Formats the comment placed before a type, field or variant. The comment must end with a newline unless it's empty.
+///
+/// This is implemented for every `Fn(&CommentContext) -> String` so closures can be used.
+pub trait CommentFormatter: Send + Sync {
+    /// Format the comment for the item described by the context. Return an empty string to omit the comment.
+    fn format(&self, ctx: &CommentContext) -> String;
+}
+
+impl<F: Fn(&CommentContext) -> String + Send + Sync> CommentFormatter for F {
+    fn format(&self, ctx: &CommentContext) -> String {
+        self(ctx)
+    }
+}
+
+/// The signature for a function responsible for exporting Typescript comments.
+pub type CommentFormatterFn = fn(&CommentContext) -> String;
+
+/// Export the Typescript comments as JS Doc comments. This means all JS Doc attributes will work.
+pub fn js_doc(ctx: &CommentContext) -> String {
+    let lines = ctx.lines();
+    if lines.is_empty() {
+        return "".to_owned();
+    }
+
+    let mut result = "/**\n".to_owned();
+    for line in lines {
+        result.push_str(&format!(" * {line}\n"));
+    }
+    result.push_str(" */\n");
+    result
+}
+
+const _: CommentFormatterFn = js_doc;
+
+/// The JSDoc `@deprecated` tag. Eg. `@deprecated Use Bar instead (since 1.0.0)`
+pub(crate) fn deprecated_tag(deprecated: Deprecated) -> String {
+    let mut tag = "@deprecated".to_string();
+    if let Some(note) = deprecated.note {
+        tag.push_str(&format!(" {note}"));
+    }
+    if let Some(since) = deprecated.since {
+        tag.push_str(&format!(" (since {since})"));
+    }
+    tag
+}
@@ -0,0 +1,1062 @@
+use std::{any::TypeId, collections::BTreeMap};
+
+use thiserror::Error;
+
+use crate::*;
+
+pub mod comments;
+mod pretty;
+
+pub use comments::{CommentContext, CommentFormatter, CommentFormatterFn, CommentKind};
+use pretty::Doc;
+pub use pretty::PrettyConfig;
+
+#[cfg(feature = "export")]
+use crate::formatter::FormatterFn;
+
+/// Allows you to configure how Specta's Typescript exporter will deal with BigInt types (i64 u64 i128 u128).
+#[derive(Default)]
+pub enum BigIntExportBehavior {
+    /// Export BigInt as a Typescript `string`
+    /// WARNING: Specta takes no responsibility that the Rust number is encoded as a string.
+    /// Make sure you instruct serde <https://github.com/serde-rs/json/issues/329#issuecomment-305608405> or your other serializer of this.
+    String,
+    /// Export BigInt as a Typescript `number`.
+    /// WARNING: `JSON.parse` in JS will truncate your number resulting in data loss so ensure your deserializer supports bigint types.
+    Number,
+    /// Export BigInt as a Typescript `BigInt`.
+    /// WARNING: Specta takes no responsibility that the Rust number is decoded into this type on the frontend.
+    /// Ensure you deserializer is able to do this.
+    BigInt,
+    /// Abort the export with an error
+    /// This is the default behavior because without integration from your serializer and deserializer we can't guarantee data loss won't occur.
+    #[default]
+    Fail,
+    /// Same as `Self::Fail` but it allows a library to configure the message shown to the end user.
+    #[doc(hidden)]
+    FailWithReason(&'static str),
+}
+
+/// The signature for a function responsible for naming the brand of a branded type. It receives the name of the type.
+pub type BrandNameFn = fn(&str) -> String;
+
+/// The comment at the start of every exported TypeScript file unless [`Output::header`](crate::export::Output::header) is configured.
+pub const HEADER: &str = "// This file has been generated by Specta. DO NOT EDIT.";
+
+/// The declaration of the `JsonValue` type used for `serde_json::Value` when [`ExportConfiguration::json_value`] is enabled.
+pub const JSON_VALUE_DECLARATION: &str = "export type JsonValue = null | boolean | number | string | JsonValue[] | { [key: string]: JsonValue }";
+
+/// What a type is replaced with when it's exported. See [`ExportConfiguration::override_type`].
+#[derive(Debug, Clone)]
+pub enum TypeOverride {
+    /// Typescript which is used as is. Eg. `import("./api").User`
+    Raw(String),
+    /// A datatype which is exported in place of the type.
+    DataType(DataType),
+}
+
+impl From<&str> for TypeOverride {
+    fn from(ts: &str) -> Self {
+        Self::Raw(ts.to_string())
+    }
+}
+
+impl From<String> for TypeOverride {
+    fn from(ts: String) -> Self {
+        Self::Raw(ts)
+    }
+}
+
+impl From<DataType> for TypeOverride {
+    fn from(typ: DataType) -> Self {
+        Self::DataType(typ)
+    }
+}
+
+/// The type which a [`TypeOverride`] replaces.
+/// References and inlined types hold the [`TypeId`] of the type while declarations only hold its [`TypeSid`] so both are kept.
+enum OverrideTarget {
+    Type { type_id: TypeId, sid: TypeSid },
+    Name(&'static str),
+    WellKnown(WellKnownType),
+}
+
+/// allows you to control the behavior of the Typescript exporter
+pub struct ExportConfiguration {
+    /// control the bigint exporting behavior
+    bigint: BigIntExportBehavior,
+    /// control the style of exported comments
+    pub(crate) comment_exporter: Option<Box<dyn CommentFormatter>>,
+    /// control how types are laid out across multiple lines
+    pretty: Option<PrettyConfig>,
+    /// brand every tuple struct with a single field instead of only those with `#[specta(brand)]`
+    brand_newtypes: bool,
+    /// control the name of the brand of branded types
+    brand_name: Option<BrandNameFn>,
+    /// override the Typescript type of well-known types
+    well_known_types: BTreeMap<WellKnownType, String>,
+    /// replace types when they are exported
+    overrides: Vec<(OverrideTarget, TypeOverride)>,
+    /// export `serde_json::Value` as `JsonValue`
+    pub(crate) json_value: bool,
+    /// export object properties and collections as immutable
+    readonly: bool,
+    /// export `DataType::Any` as `unknown`
+    any_as_unknown: bool,
+    /// Configure whether or not to export types by default.
+    /// This can be overridden on a type basis by using `#[specta(export)]`
+    #[cfg(feature = "export")]
+    pub(crate) export_by_default: Option<bool>,
+    /// Configure a function which is responsible for formatting the exported file
+    #[cfg(feature = "export")]
+    pub(crate) formatter: Option<FormatterFn>,
+}
+
+impl ExportConfiguration {
+    /// Construct a new `ExportConfiguration`
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configure the BigInt handling behaviour
+    pub fn bigint(mut self, bigint: BigIntExportBehavior) -> Self {
+        self.bigint = bigint;
+        self
+    }
+
+    /// Configure a function which is responsible for styling the comments to be exported
+    pub fn comment_style(mut self, exporter: Option<CommentFormatterFn>) -> Self {
+        self.comment_exporter = exporter.map(|f| Box::new(f) as Box<dyn CommentFormatter>);
+        self
+    }
+
+    /// Configure a formatter which is responsible for styling the comments to be exported.
+    /// Unlike [`comment_style`](Self::comment_style) this accepts closures so the formatter can capture state.
+    pub fn comment_formatter(mut self, formatter: impl CommentFormatter + 'static) -> Self {
+        self.comment_exporter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Configure how exported types are laid out across multiple lines. `None` exports each type on a single line.
+    /// Types with doc comments on their fields or variants are always laid out across multiple lines using the default [`PrettyConfig`] so the comments can be placed on their own lines.
+    pub fn pretty(mut self, pretty: Option<PrettyConfig>) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Configure whether every tuple struct with a single field is exported as a branded type, not only those with `#[specta(brand)]`.
+    /// Eg. `export type UserId = string & { readonly __brand: "UserId" }`
+    pub fn brand_newtypes(mut self, brand: bool) -> Self {
+        self.brand_newtypes = brand;
+        self
+    }
+
+    /// Configure a function which is responsible for naming the brand of branded types. `None` uses the name of the type.
+    pub fn brand_name(mut self, brand_name: Option<BrandNameFn>) -> Self {
+        self.brand_name = brand_name;
+        self
+    }
+
+    /// Configure the Typescript type a [`WellKnownType`] is exported as. By default they are exported as `string`.
+    /// Eg. ``.well_known_type(WellKnownType::Date, "`${number}-${number}-${number}`")``
+    pub fn well_known_type(mut self, ty: WellKnownType, ts: impl Into<String>) -> Self {
+        self.well_known_types.insert(ty, ts.into());
+        self
+    }
+
+    /// Configure a replacement for the type `T` wherever it's exported. Eg. `conf.override_type::<serde_json::Value>("JsonValue")`
+    ///
+    /// This applies to references to the type, its own declaration and places where it's inlined.
+    /// Types which are inlined as a primitive (Eg. `PathBuf` as `string`) can't be told apart from the primitive so they can't be overridden.
+    /// References to a generic type are only replaced when their generic arguments match `T` (Eg. `Page<User>` but not `Page<Post>`) while its declaration is always replaced.
+    pub fn override_type<T: Type + 'static>(mut self, with: impl Into<TypeOverride>) -> Self {
+        let target = match T::inline(
+            DefOpts {
+                parent_inline: true,
+                type_map: &mut TypeDefs::default(),
+            },
+            &[],
+        ) {
+            DataType::WellKnown(ty) => OverrideTarget::WellKnown(ty),
+            _ => OverrideTarget::Type {
+                type_id: TypeId::of::<T>(),
+                sid: T::SID,
+            },
+        };
+        self.overrides.push((target, with.into()));
+        self
+    }
+
+    /// Configure a replacement for every type with the name `name`. This is useful for types which don't implement [`Type`].
+    /// Unlike [`override_type`](Self::override_type) this also replaces unrelated types which happen to share the name.
+    /// See [`override_type`](Self::override_type) for where it applies.
+    pub fn override_type_named(
+        mut self,
+        name: &'static str,
+        with: impl Into<TypeOverride>,
+    ) -> Self {
+        self.overrides
+            .push((OverrideTarget::Name(name), with.into()));
+        self
+    }
+
+    /// Configure whether `serde_json::Value` is exported as a `JsonValue` type which describes any JSON value instead of `any`.
+    /// The declaration of `JsonValue` ([`JSON_VALUE_DECLARATION`]) is included when exporting a file with [`export::ts`](crate::export::ts) or [`Language::export_all`].
+    pub fn json_value(mut self, json_value: bool) -> Self {
+        self.json_value = json_value;
+        self
+    }
+
+    /// Configure whether every object property, list and record is exported as immutable. Eg. `{ readonly tags: readonly string[] }`
+    /// This can be enabled for a single type by using `#[specta(readonly)]`.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Configure whether untyped values are exported as `unknown` instead of `any` so they must be narrowed before they are used.
+    pub fn any_as_unknown(mut self, any_as_unknown: bool) -> Self {
+        self.any_as_unknown = any_as_unknown;
+        self
+    }
+
+    /// Configure whether or not to export types by default.
+    /// Note: This parameter only work if this configuration if passed into [crate::export::ts]
+    #[cfg(feature = "export")]
+    pub fn export_by_default(mut self, x: Option<bool>) -> Self {
+        self.export_by_default = x;
+        self
+    }
+
+    /// Configure a function which is responsible for formatting the exported file. Eg. [`formatter::prettier`](crate::formatter::prettier)
+    /// Note: This parameter only work if this configuration if passed into [crate::export::ts]
+    #[cfg(feature = "export")]
+    pub fn formatter(mut self, formatter: Option<FormatterFn>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+}
+
+impl ExportConfiguration {
+    /// Find the override for a type within another type.
+    fn find_override(&self, typ: &DataType) -> Option<&TypeOverride> {
+        let (name, id) = match typ {
+            DataType::WellKnown(ty) => {
+                return self
+                    .overrides
+                    .iter()
+                    .find_map(|(target, with)| match target {
+                        OverrideTarget::WellKnown(t) if t == ty => Some(with),
+                        _ => None,
+                    })
+            }
+            DataType::Reference { name, type_id, .. }
+            | DataType::Enum(EnumType { name, type_id, .. }) => (name, type_id),
+            // Enum variants are objects without a type id so they aren't mistaken for a type with the same name
+            DataType::Object(ObjectType {
+                name,
+                type_id: Some(type_id),
+                ..
+            }) => (name, type_id),
+            _ => return None,
+        };
+
+        self.overrides
+            .iter()
+            .find_map(|(target, with)| match target {
+                OverrideTarget::Type { type_id, .. } if type_id == id => Some(with),
+                OverrideTarget::Name(n) if n == name => Some(with),
+                _ => None,
+            })
+    }
+
+    /// Find the override for the declaration of a type.
+    fn find_declaration_override(&self, def: &DataTypeExt) -> Option<&TypeOverride> {
+        self.overrides
+            .iter()
+            .find_map(|(target, with)| match target {
+                OverrideTarget::Type { sid, .. } if *sid == def.sid => Some(with),
+                OverrideTarget::Name(name) if *name == def.name => Some(with),
+                _ => None,
+            })
+    }
+}
+
+impl Default for ExportConfiguration {
+    fn default() -> Self {
+        Self {
+            bigint: Default::default(),
+            comment_exporter: Some(Box::new(comments::js_doc as CommentFormatterFn)),
+            pretty: None,
+            brand_newtypes: false,
+            brand_name: None,
+            well_known_types: Default::default(),
+            overrides: vec![],
+            json_value: false,
+            readonly: false,
+            any_as_unknown: false,
+            #[cfg(feature = "export")]
+            export_by_default: None,
+            #[cfg(feature = "export")]
+            formatter: None,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum TsExportError {
+    #[error("Failed to export type '{}' on field `{}`: {err}", .ty_name.unwrap_or_default(), .field_name.unwrap_or_default())]
+    WithCtx {
+        // TODO: Handle this better. Make `ty_name` non optional
+        ty_name: Option<&'static str>,
+        field_name: Option<&'static str>,
+        err: Box<TsExportError>,
+    },
+    #[error("Your Specta configuration forbids exporting BigInt types (i64, u64, i128, u128) because we don't know if your se/deserializer supports it. You can change this behavior by editing your `ExportConfiguration`")]
+    BigIntForbidden,
+    #[error("Cannot export anonymous object. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousObject,
+    #[error("Cannot export anonymous enum. Try wrapping the type in a tuple struct which has the `ToDataType` derive macro on it.")]
+    AnonymousEnum,
+    #[error("You have defined a type with the name '{0}' which is a reserved name by the Typescript exporter. Try renaming it or using `#[specta(rename = \"new name\")]`")]
+    ForbiddenTypeName(&'static str),
+    #[error("You have defined a field '{1}' on type '{0}' which has a name that is reserved name by the Typescript exporter. Try renaming it or using `#[specta(rename = \"new name\")]`")]
+    ForbiddenFieldName(String, &'static str),
+    #[error("Type cannot be exported: {0:?}")]
+    CannotExport(Box<DataTypeExt>),
+    #[error("Cannot export type due to an internal error. This likely is a bug in Specta itself and not your code: {0}")]
+    InternalError(&'static str),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to format the exported file: {0}")]
+    Format(std::io::Error),
+    #[error("{0}")]
+    Other(String),
+}
+
+/// The [TypeScript](https://www.typescriptlang.org) [`Language`](crate::Language).
+pub struct Typescript;
+
+impl Language for Typescript {
+    type Config = ExportConfiguration;
+    type Error = TsExportError;
+
+    const HEADER: Option<&'static str> = Some(HEADER);
+
+    fn export_datatype(conf: &Self::Config, def: &DataTypeExt) -> Result<String, Self::Error> {
+        export_datatype(conf, def)
+    }
+
+    fn export_many<'a>(
+        conf: &Self::Config,
+        types: impl IntoIterator<Item = &'a DataTypeExt>,
+    ) -> Result<String, Self::Error> {
+        let helpers = conf.json_value.then(|| JSON_VALUE_DECLARATION.to_string());
+
+        Ok(helpers
+            .into_iter()
+            .map(Ok)
+            .chain(types.into_iter().map(|def| export_datatype(conf, def)))
+            .collect::<Result<Vec<_>, _>>()?
+            .join("\n\n"))
+    }
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to a TypeScript string with an export.
+/// Eg. `export type Foo = { demo: string; };`
+pub fn export<T: Type>(conf: &ExportConfiguration) -> Result<String, TsExportError> {
+    export_datatype(
+        conf,
+        &T::definition(DefOpts {
+            parent_inline: true,
+            type_map: &mut TypeDefs::default(),
+        }),
+    )
+}
+
+/// Convert a type which implements [`Type`](crate::Type) to a TypeScript string.
+/// Eg. `{ demo: string; };`
+pub fn inline<T: Type>(conf: &ExportConfiguration) -> Result<String, TsExportError> {
+    datatype_inner(
+        conf,
+        &T::inline(
+            DefOpts {
+                parent_inline: true,
+                type_map: &mut TypeDefs::default(),
+            },
+            &[],
+        ),
+        None,
+        conf.readonly || T::READONLY,
+    )
+    .map(|doc| doc.flat())
+}
+
+/// Convert a DataType to a TypeScript string with an export.
+/// Eg. `export type Foo = { demo: string; };`
+///
+// TODO: Accept `DataTypeExt` or `DataType`. This is hard because we take it by reference
+pub fn export_datatype(
+    conf: &ExportConfiguration,
+    def: &DataTypeExt,
+) -> Result<String, TsExportError> {
+    let readonly = conf.readonly || def.readonly;
+    let type_override = conf.find_declaration_override(def);
+    let depth = Some(0);
+    let doc = match type_override {
+        Some(type_override) => export_override(conf, type_override, depth, readonly),
+        None => datatype_inner(conf, &def.inner, depth, readonly),
+    }
+    .map_err(|err| TsExportError::WithCtx {
+        ty_name: Some(def.name),
+        field_name: None,
+        err: Box::new(err),
+    })?;
+
+    // The brand makes a newtype incompatible with other types of the same structure. Eg. `string & { readonly __brand: "UserId" }`
+    let doc = match &def.inner {
+        DataType::Tuple(TupleType { fields, .. })
+            if fields.len() == 1
+                && (def.brand || conf.brand_newtypes)
+                && type_override.is_none() =>
+        {
+            let brand = match conf.brand_name {
+                Some(brand_name) => brand_name(def.name),
+                None => def.name.to_string(),
+            };
+            let doc = match &fields[0] {
+                DataType::Nullable(_) | DataType::Enum(_) => Doc::paren(doc),
+                _ => doc,
+            };
+
+            Doc::intersection([
+                doc,
+                Doc::object(vec![(
+                    String::new(),
+                    Doc::text(format!("readonly __brand: \"{brand}\"")),
+                )]),
+            ])
+        }
+        _ => doc,
+    };
+
+    let head = match &def.inner {
+        // Named struct
+        DataType::Object(ObjectType {
+            name,
+            generics,
+            fields,
+            ..
+        }) => {
+            if name.is_empty() {
+                return Err(TsExportError::AnonymousObject);
+            } else if let Some(name) = RESERVED_WORDS.iter().find(|v| *v == name) {
+                return Err(TsExportError::ForbiddenTypeName(name));
+            }
+
+            match fields.len() {
+                0 => format!("type {name} = "),
+                _ => {
+                    let generics = match generics.len() {
+                        0 => "".into(),
+                        _ => format!("<{}>", generics.to_vec().join(", ")),
+                    };
+
+                    format!("type {name}{generics} = ")
+                }
+            }
+        }
+        // Enum
+        DataType::Enum(EnumType { name, generics, .. }) => {
+            if name.is_empty() {
+                return Err(TsExportError::AnonymousEnum);
+            } else if let Some(name) = RESERVED_WORDS.iter().find(|v| *v == name) {
+                return Err(TsExportError::ForbiddenTypeName(name));
+            }
+
+            let generics = match generics.len() {
+                0 => "".into(),
+                _ => format!("<{}>", generics.to_vec().join(", ")),
+            };
+
+            format!("type {name}{generics} = ")
+        }
+        // Unnamed struct
+        DataType::Tuple(TupleType { name, generics, .. }) => {
+            if let Some(name) = RESERVED_WORDS.iter().find(|v| *v == name) {
+                return Err(TsExportError::ForbiddenTypeName(name));
+            }
+
+            let generics = match generics.len() {
+                0 => "".into(),
+                _ => format!("<{}>", generics.to_vec().join(", ")),
+            };
+
+            format!("type {name}{generics} = ")
+        }
+        _ => return Err(TsExportError::CannotExport(Box::new(def.clone()))), // TODO: Can this be enforced at a type system level
+    };
+
+    let comments = export_comments(
+        conf,
+        CommentContext {
+            kind: CommentKind::Type,
+            name: def.name,
+            comments: def.comments,
+            doc_tags: def.doc_tags,
+            deprecated: def.deprecated,
+            constraints: Default::default(),
+            depth: 0,
+        },
+    );
+
+    // Field and variant comments must be placed on their own lines so types with them are always laid out across multiple lines
+    let declaration = match &conf.pretty {
+        Some(pretty) => pretty::pretty(pretty, &format!("export {head}"), &doc),
+        None if doc.has_comments() => {
+            pretty::pretty(&Default::default(), &format!("export {head}"), &doc)
+        }
+        None => format!("export {head}{}", doc.flat()),
+    };
+    Ok(format!("{comments}{declaration}"))
+}
+
+/// Convert a DataType to a TypeScript string
+/// Eg. `{ demo: string; }`
+pub fn datatype(conf: &ExportConfiguration, typ: &DataType) -> Result<String, TsExportError> {
+    datatype_inner(conf, typ, None, conf.readonly).map(|doc| doc.flat())
+}
+
+// When `depth` is set the doc comments of fields and variants are placed before them. It's how deeply `typ` is nested within the exported type.
+// Comments are followed by a newline so declarations with them are laid out by [`pretty`].
+// When `readonly` is set properties, lists and records are marked as immutable.
+fn datatype_inner(
+    conf: &ExportConfiguration,
+    typ: &DataType,
+    depth: Option<usize>,
+    readonly: bool,
+) -> Result<Doc, TsExportError> {
+    if let Some(type_override) = conf.find_override(typ) {
+        return export_override(conf, type_override, depth, readonly);
+    }
+
+    let ro = match readonly {
+        true => "readonly ",
+        false => "",
+    };
+
+    Ok(match &typ {
+        DataType::Any => match conf.any_as_unknown {
+            true => Doc::text("unknown"),
+            false => Doc::text("any"),
+        },
+        primitive_def!(i8 i16 i32 u8 u16 u32 f32 f64) => Doc::text("number"),
+        primitive_def!(usize isize i64 u64 i128 u128) => match conf.bigint {
+            BigIntExportBehavior::String => Doc::text("string"),
+            BigIntExportBehavior::Number => Doc::text("number"),
+            BigIntExportBehavior::BigInt => Doc::text("BigInt"),
+            BigIntExportBehavior::Fail => return Err(TsExportError::BigIntForbidden),
+            BigIntExportBehavior::FailWithReason(reason) => {
+                return Err(TsExportError::Other(reason.to_owned()))
+            }
+        },
+        primitive_def!(String char) => Doc::text("string"),
+        primitive_def!(bool) => Doc::text("boolean"),
+        DataType::Literal(literal) => Doc::text(literal.to_ts()),
+        DataType::WellKnown(ty) => match conf.well_known_types.get(ty) {
+            Some(ts) => Doc::text(ts.clone()),
+            None if *ty == WellKnownType::Json && conf.json_value => Doc::text("JsonValue"),
+            None => datatype_inner(conf, &ty.repr(), depth, readonly)?,
+        },
+        DataType::Nullable(def) => Doc::union([
+            (String::new(), datatype_inner(conf, def, depth, readonly)?),
+            (String::new(), Doc::text("null")),
+        ]),
+        // We use this isn't of `Record<K, V>` to avoid issues with circular references.
+        DataType::Record(def) => Doc::object(vec![(
+            String::new(),
+            Doc::Concat(vec![
+                Doc::text(format!("{ro}[key: ")),
+                datatype_inner(conf, &def.0, depth, readonly)?,
+                Doc::text("]: "),
+                datatype_inner(conf, &def.1, depth, readonly)?,
+            ]),
+        )]),
+        // We use `T[]` instead of `Array<T>` to avoid issues with circular references.
+        // Sets are serialized as JSON arrays so they are exported the same as lists.
+        DataType::List(def) | DataType::Set(def) => {
+            let item = datatype_inner(conf, def, depth, readonly)?;
+            match readonly
+                && (matches!(def.as_ref(), DataType::Nullable(_) | DataType::Enum(_))
+                    || is_readonly_array(conf, def))
+            {
+                // `readonly` only applies to array types so a union or readonly array must be wrapped. Eg. `readonly (string | null)[]` or `readonly (readonly string[])[]`
+                true => Doc::Concat(vec![
+                    Doc::text("readonly "),
+                    Doc::paren(item),
+                    Doc::text("[]"),
+                ]),
+                false => Doc::Concat(vec![Doc::text(ro), item, Doc::text("[]")]),
+            }
+        }
+        DataType::Tuple(TupleType { fields, .. }) => match &fields[..] {
+            [] => Doc::text("null"),
+            [ty] => datatype_inner(conf, ty, depth, readonly)?,
+            tys => Doc::Concat(vec![
+                Doc::text(ro),
+                Doc::list(
+                    '[',
+                    ']',
+                    tys.iter()
+                        .map(|v| datatype_inner(conf, v, depth, readonly))
+                        .collect::<Result<Vec<_>, _>>()?,
+                ),
+            ]),
+        },
+        DataType::Object(ObjectType {
+            fields, tag, name, ..
+        }) => match &fields[..] {
+            [] => Doc::text("null"),
+            fields => {
+                let nested = depth.map(|d| d + 1);
+                let mut field_sections = fields
+                    .iter()
+                    .filter(|f| f.flatten)
+                    .map(|field| {
+                        datatype_inner(conf, &field.ty, nested, readonly)
+                            .map(Doc::paren)
+                            .map_err(|err| TsExportError::WithCtx {
+                                ty_name: None,
+                                field_name: Some(field.name),
+                                err: Box::new(err),
+                            })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let mut unflattened_fields = fields
+                    .iter()
+                    .filter(|f| !f.flatten)
+                    .map(|field| {
+                        let field_name_safe = sanitise_name(name, field.name)?;
+                        let field_ts = datatype_inner(conf, &field.ty, nested, readonly);
+
+                        // https://github.com/oscartbeaumont/rspc/issues/100#issuecomment-1373092211
+                        let (key, result) = match field.optional {
+                            true => (
+                                format!("{field_name_safe}?"),
+                                match &field.ty {
+                                    DataType::Nullable(_) => field_ts,
+                                    _ => field_ts.map(|v| {
+                                        Doc::union([
+                                            (String::new(), v),
+                                            (String::new(), Doc::text("null")),
+                                        ])
+                                    }),
+                                },
+                            ),
+                            false => (field_name_safe, field_ts),
+                        };
+
+                        result
+                            .map(|v| {
+                                (
+                                    field_comments(conf, field, nested),
+                                    Doc::Concat(vec![Doc::text(format!("{ro}{key}: ")), v]),
+                                )
+                            })
+                            .map_err(|err| TsExportError::WithCtx {
+                                ty_name: None,
+                                field_name: Some(field.name),
+                                err: Box::new(err),
+                            })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if let Some((tag, value)) = tag {
+                    unflattened_fields
+                        .push((String::new(), Doc::text(format!("{ro}{tag}: \"{value}\""))));
+                }
+
+                if !unflattened_fields.is_empty() {
+                    field_sections.push(Doc::object(unflattened_fields));
+                }
+
+                match field_sections.len() {
+                    1 => field_sections.remove(0),
+                    _ => Doc::intersection(field_sections),
+                }
+            }
+        },
+        DataType::Enum(EnumType {
+            name,
+            variants,
+            repr,
+            ..
+        }) => match &variants[..] {
+            [] => Doc::text("never"),
+            variants => Doc::union(
+                variants
+                    .iter()
+                    .map(|variant| {
+                        let sanitised_name = sanitise_name(name, variant.name())?;
+                        let nested = depth.map(|d| d + 1);
+                        let doc = nested
+                            .map(|depth| {
+                                item_comments(
+                                    conf,
+                                    CommentContext {
+                                        kind: CommentKind::Variant,
+                                        name: variant.name(),
+                                        comments: variant.comments(),
+                                        doc_tags: &[],
+                                        deprecated: variant.deprecated(),
+                                        constraints: Default::default(),
+                                        depth,
+                                    },
+                                )
+                            })
+                            .unwrap_or_default();
+                        let tag_field = |tag: &str| {
+                            (
+                                String::new(),
+                                Doc::text(format!("{ro}{tag}: \"{sanitised_name}\"")),
+                            )
+                        };
+
+                        let member =
+                            match (repr, variant) {
+                                (EnumRepr::Internal { tag }, EnumVariant::Unit(..)) => {
+                                    Doc::object(vec![tag_field(tag)])
+                                }
+                                (EnumRepr::Internal { tag }, EnumVariant::Unnamed(tuple)) => {
+                                    let typ = datatype_inner(
+                                        conf,
+                                        &DataType::Tuple(tuple.clone()),
+                                        nested,
+                                        readonly,
+                                    )
+                                    .map_err(|err| TsExportError::WithCtx {
+                                        ty_name: None,
+                                        field_name: Some(variant.name()),
+                                        err: Box::new(err),
+                                    })?;
+
+                                    Doc::paren(Doc::intersection([
+                                        Doc::object(vec![tag_field(tag)]),
+                                        typ,
+                                    ]))
+                                }
+                                (EnumRepr::Internal { tag }, EnumVariant::Named(obj)) => {
+                                    let mut fields = vec![tag_field(tag)];
+
+                                    fields.extend(
+                                        obj.fields
+                                            .iter()
+                                            .map(|v| field_doc(conf, name, v, nested, readonly))
+                                            .collect::<Result<Vec<_>, _>>()?,
+                                    );
+
+                                    Doc::object(fields)
+                                }
+                                (EnumRepr::External, EnumVariant::Unit(..)) => {
+                                    Doc::text(format!("\"{sanitised_name}\""))
+                                }
+                                (EnumRepr::External, v) => {
+                                    let ts_values =
+                                        datatype_inner(conf, &v.data_type(), nested, readonly)
+                                            .map_err(|err| TsExportError::WithCtx {
+                                                ty_name: None,
+                                                field_name: Some(variant.name()),
+                                                err: Box::new(err),
+                                            })?;
+
+                                    Doc::object(vec![(
+                                        String::new(),
+                                        Doc::Concat(vec![
+                                            Doc::text(format!("{ro}{sanitised_name}: ")),
+                                            ts_values,
+                                        ]),
+                                    )])
+                                }
+                                (EnumRepr::Untagged, EnumVariant::Unit(..)) => Doc::text("null"),
+                                (EnumRepr::Untagged, v) => {
+                                    datatype_inner(conf, &v.data_type(), nested, readonly).map_err(
+                                        |err| TsExportError::WithCtx {
+                                            ty_name: None,
+                                            field_name: Some(variant.name()),
+                                            err: Box::new(err),
+                                        },
+                                    )?
+                                }
+                                (EnumRepr::Adjacent { tag, .. }, EnumVariant::Unit(..)) => {
+                                    Doc::object(vec![tag_field(tag)])
+                                }
+                                (EnumRepr::Adjacent { tag, content }, v) => {
+                                    let ts_values =
+                                        datatype_inner(conf, &v.data_type(), nested, readonly)
+                                            .map_err(|err| TsExportError::WithCtx {
+                                                ty_name: None,
+                                                field_name: Some(variant.name()),
+                                                err: Box::new(err),
+                                            })?;
+
+                                    Doc::object(vec![
+                                        tag_field(tag),
+                                        (
+                                            String::new(),
+                                            Doc::Concat(vec![
+                                                Doc::text(format!("{ro}{content}: ")),
+                                                ts_values,
+                                            ]),
+                                        ),
+                                    ])
+                                }
+                            };
+
+                        Ok((doc, member))
+                    })
+                    .collect::<Result<Vec<_>, TsExportError>>()?,
+            ),
+        },
+        DataType::Reference { name, generics, .. } => match &generics[..] {
+            [] => Doc::text(*name),
+            generics => Doc::Concat(vec![
+                Doc::text(*name),
+                Doc::list(
+                    '<',
+                    '>',
+                    generics
+                        .iter()
+                        .map(|v| datatype_inner(conf, v, depth, readonly))
+                        .collect::<Result<Vec<_>, _>>()?,
+                ),
+            ]),
+        },
+        DataType::Generic(GenericType(ident)) => Doc::text(ident.to_string()),
+        DataType::Placeholder => {
+            return Err(TsExportError::InternalError(
+                "Attempted to export a placeholder!",
+            ))
+        }
+    })
+}
+
+fn export_override(
+    conf: &ExportConfiguration,
+    type_override: &TypeOverride,
+    depth: Option<usize>,
+    readonly: bool,
+) -> Result<Doc, TsExportError> {
+    match type_override {
+        TypeOverride::Raw(ts) => Ok(Doc::text(ts.clone())),
+        TypeOverride::DataType(typ) => datatype_inner(conf, typ, depth, readonly),
+    }
+}
+
+/// Whether the type is exported as a readonly array or tuple when `readonly` is set. Eg. `readonly string[]`
+fn is_readonly_array(conf: &ExportConfiguration, typ: &DataType) -> bool {
+    match conf.find_override(typ) {
+        Some(TypeOverride::Raw(_)) => false,
+        Some(TypeOverride::DataType(typ)) => is_readonly_array(conf, typ),
+        None => match typ {
+            DataType::List(_) | DataType::Set(_) => true,
+            DataType::Tuple(TupleType { fields, .. }) => match &fields[..] {
+                [] => false,
+                [ty] => is_readonly_array(conf, ty),
+                _ => true,
+            },
+            _ => false,
+        },
+    }
+}
+
+impl LiteralType {
+    fn to_ts(&self) -> String {
+        match self {
+            Self::i8(v) => v.to_string(),
+            Self::i16(v) => v.to_string(),
+            Self::i32(v) => v.to_string(),
+            Self::u8(v) => v.to_string(),
+            Self::u16(v) => v.to_string(),
+            Self::u32(v) => v.to_string(),
+            Self::f32(v) => v.to_string(),
+            Self::f64(v) => v.to_string(),
+            Self::bool(v) => v.to_string(),
+            Self::String(v) => format!(r#""{v}""#),
+            Self::None => "null".to_string(),
+        }
+    }
+}
+
+/// convert an object field into a Typescript string
+pub fn object_field_to_ts(
+    conf: &ExportConfiguration,
+    type_name: &str,
+    field: &ObjectField,
+) -> Result<String, TsExportError> {
+    field_doc(conf, type_name, field, None, conf.readonly).map(|(_, doc)| doc.flat())
+}
+
+// The field along with its doc comments which are placed before it.
+fn field_doc(
+    conf: &ExportConfiguration,
+    type_name: &str,
+    field: &ObjectField,
+    depth: Option<usize>,
+    readonly: bool,
+) -> Result<(String, Doc), TsExportError> {
+    let field_name_safe = sanitise_name(type_name, field.name)?;
+
+    let (key, ty) = match field.optional {
+        true => (
+            format!("{field_name_safe}?"),
+            match &field.ty {
+                DataType::Nullable(ty) => ty.as_ref(),
+                ty => ty,
+            },
+        ),
+        false => (field_name_safe, &field.ty),
+    };
+
+    let ro = match readonly {
+        true => "readonly ",
+        false => "",
+    };
+
+    Ok((
+        field_comments(conf, field, depth),
+        Doc::Concat(vec![
+            Doc::text(format!("{ro}{key}: ")),
+            datatype_inner(conf, ty, depth, readonly)?,
+        ]),
+    ))
+}
+
+/// Render the doc comments of a field so they can be placed directly before it. Nothing is rendered when `depth` is `None`.
+fn field_comments(conf: &ExportConfiguration, field: &ObjectField, depth: Option<usize>) -> String {
+    depth
+        .map(|depth| {
+            item_comments(
+                conf,
+                CommentContext {
+                    kind: CommentKind::Field,
+                    name: field.name,
+                    comments: field.comments,
+                    doc_tags: field.doc_tags,
+                    deprecated: field.deprecated,
+                    constraints: field.constraints,
+                    depth,
+                },
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// Render the doc comments, documentation tags and deprecation of a type, field or variant with the configured comment exporter.
+fn export_comments(conf: &ExportConfiguration, ctx: CommentContext) -> String {
+    conf.comment_exporter
+        .as_ref()
+        .map(|exporter| exporter.format(&ctx))
+        .unwrap_or_default()
+}
+
+/// Render the comments of a field or variant. The layout places them on their own line so the trailing newline isn't included.
+fn item_comments(conf: &ExportConfiguration, ctx: CommentContext) -> String {
+    export_comments(conf, ctx).trim_end().to_string()
+}
+
+/// sanitise a string to be a valid Typescript key
+pub fn sanitise_name(type_name: &str, field_name: &str) -> Result<String, TsExportError> {
+    if let Some(name) = RESERVED_WORDS.iter().find(|v| **v == field_name) {
+        return Err(TsExportError::ForbiddenFieldName(
+            type_name.to_owned(),
+            name,
+        ));
+    }
+
+    Ok(if !is_valid_ident(field_name) {
+        format!(r#""{field_name}""#)
+    } else {
+        field_name.to_string()
+    })
+}
+
+/// check if a string can be used as a Javascript identifier without quoting it
+pub(crate) fn is_valid_ident(ident: &str) -> bool {
+    ident
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        && ident
+            .chars()
+            .next()
+            .map(|first| !first.is_numeric())
+            .unwrap_or(true)
+}
+
+// Taken from: https://github.com/microsoft/TypeScript/issues/2536#issuecomment-87194347
+pub(crate) const RESERVED_WORDS: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "as",
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+    "any",
+    "boolean",
+    "constructor",
+    "declare",
+    "get",
+    "module",
+    "require",
+    "number",
+    "set",
+    "string",
+    "symbol",
+    "type",
+    "from",
+    "of",
+    "namespace",
+    "async",
+    "await",
+];
@@ -0,0 +1,257 @@
+//! An indentation-aware printer which lays out exported Typescript across multiple lines.
+//!
+//! [`datatype_inner`](super::datatype_inner) describes a type as a [`Doc`] made of unions, intersections and bracketed groups. A group or union is only broken across lines when it doesn't fit within the line width.
+//! Comments before fields and union members always break their group or union so they can be placed on their own lines.
+
+/// Configure how the Typescript exporter lays out types across multiple lines.
+#[derive(Debug, Clone)]
+pub struct PrettyConfig {
+    /// The string used for each level of indentation.
+    pub indent: String,
+    /// The maximum width of a line before a type is broken across multiple lines.
+    pub line_width: usize,
+}
+
+impl Default for PrettyConfig {
+    fn default() -> Self {
+        Self {
+            indent: "  ".into(),
+            line_width: 80,
+        }
+    }
+}
+
+/// A Typescript type along with the places it can be broken across lines.
+pub(crate) enum Doc {
+    /// Text which is never broken. Eg. `string` or a template literal.
+    Text(String),
+    /// Documents which are printed one after another. Eg. `string` followed by `[]`.
+    Concat(Vec<Doc>),
+    /// `A | B`. Each member can be preceded by a comment.
+    Union(Vec<(String, Doc)>),
+    /// `A & B`
+    Intersection(Vec<Doc>),
+    /// Items surrounded by brackets. Eg. `{ a: string; b: number }`, `[string, number]`, `Foo<A, B>` or `(A | B)`.
+    /// Each item can be preceded by a comment.
+    Group {
+        open: char,
+        close: char,
+        items: Vec<(String, Doc)>,
+    },
+}
+
+impl Doc {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// `A | B | null` is a single union so members which are unions themselves are merged into it.
+    pub fn union(members: impl IntoIterator<Item = (String, Doc)>) -> Self {
+        Self::Union(
+            members
+                .into_iter()
+                .flat_map(|(comment, doc)| match doc {
+                    Self::Union(members) if comment.is_empty() => members,
+                    doc => vec![(comment, doc)],
+                })
+                .collect(),
+        )
+    }
+
+    /// Members which are intersections themselves are merged into it.
+    pub fn intersection(docs: impl IntoIterator<Item = Doc>) -> Self {
+        Self::Intersection(
+            docs.into_iter()
+                .flat_map(|doc| match doc {
+                    Self::Intersection(docs) => docs,
+                    doc => vec![doc],
+                })
+                .collect(),
+        )
+    }
+
+    /// An object with one property per item. Eg. `{ a: string; b: number }`
+    pub fn object(items: Vec<(String, Doc)>) -> Self {
+        Self::Group {
+            open: '{',
+            close: '}',
+            items,
+        }
+    }
+
+    /// Items separated by commas. Eg. `[string, number]`
+    pub fn list(open: char, close: char, items: impl IntoIterator<Item = Doc>) -> Self {
+        Self::Group {
+            open,
+            close,
+            items: items.into_iter().map(|doc| (String::new(), doc)).collect(),
+        }
+    }
+
+    /// Wrap the type in parentheses. Eg. `(string | null)`
+    pub fn paren(doc: Doc) -> Self {
+        Self::list('(', ')', [doc])
+    }
+
+    /// Whether a field or union member is preceded by a comment anywhere within the type.
+    pub fn has_comments(&self) -> bool {
+        match self {
+            Self::Text(_) => false,
+            Self::Concat(docs) | Self::Intersection(docs) => docs.iter().any(Doc::has_comments),
+            Self::Union(items) | Self::Group { items, .. } => items
+                .iter()
+                .any(|(comment, doc)| !comment.is_empty() || doc.has_comments()),
+        }
+    }
+
+    /// The type laid out on a single line. Comments are followed by a newline so types with them must be laid out by [`pretty`] instead.
+    pub fn flat(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Concat(docs) => docs.iter().map(Doc::flat).collect(),
+            Self::Union(members) => flat_items(members, " | "),
+            Self::Intersection(docs) => docs.iter().map(Doc::flat).collect::<Vec<_>>().join(" & "),
+            Self::Group {
+                open: '{', items, ..
+            } => format!("{{ {} }}", flat_items(items, "; ")),
+            Self::Group { open, close, items } => {
+                format!("{open}{}{close}", flat_items(items, ", "))
+            }
+        }
+    }
+}
+
+fn flat_items(items: &[(String, Doc)], sep: &str) -> String {
+    items
+        .iter()
+        .map(|(comment, doc)| match comment.is_empty() {
+            true => doc.flat(),
+            false => format!("{comment}\n{}", doc.flat()),
+        })
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+/// Lay out the Typescript type which follows the `prefix` (Eg. `export type Foo = `).
+/// Objects at the root of the type always have one field per line.
+pub(crate) fn pretty(conf: &PrettyConfig, prefix: &str, doc: &Doc) -> String {
+    let root = match doc {
+        Doc::Union(members) if matches!(&members[..], [(comment, _)] if comment.is_empty()) => {
+            &members[0].1
+        }
+        doc => doc,
+    };
+
+    let mut printer = Printer {
+        conf,
+        out: prefix.to_string(),
+    };
+    printer.doc(root, 0, matches!(root, Doc::Group { open: '{', .. }));
+    printer.out
+}
+
+struct Printer<'a> {
+    conf: &'a PrettyConfig,
+    out: String,
+}
+
+impl Printer<'_> {
+    fn column(&self) -> usize {
+        self.out
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+    }
+
+    fn fits(&self, s: &str) -> bool {
+        !s.contains('\n') && self.column() + s.chars().count() <= self.conf.line_width
+    }
+
+    fn newline(&mut self, level: usize) {
+        self.out.truncate(self.out.trim_end_matches(' ').len());
+        self.out.push('\n');
+        for _ in 0..level {
+            self.out.push_str(&self.conf.indent);
+        }
+    }
+
+    fn doc(&mut self, doc: &Doc, level: usize, force: bool) {
+        match doc {
+            Doc::Text(text) => self.out.push_str(text),
+            Doc::Concat(docs) => {
+                for doc in docs {
+                    self.doc(doc, level, force);
+                }
+            }
+            Doc::Union(members) => self.union(members, level, force),
+            Doc::Intersection(docs) => {
+                for (i, doc) in docs.iter().enumerate() {
+                    if i != 0 {
+                        self.out.push_str(" & ");
+                    }
+                    self.doc(doc, level, force);
+                }
+            }
+            Doc::Group { open, close, items }
+                if !items.is_empty() && (force || !self.fits(&doc.flat())) =>
+            {
+                self.out.push(*open);
+                for (comment, item) in items {
+                    self.newline(level + 1);
+                    self.comment(comment, level + 1);
+                    self.doc(item, level + 1, false);
+                    match open {
+                        '{' => self.out.push(';'),
+                        '(' => {}
+                        _ => self.out.push(','),
+                    }
+                }
+                self.newline(level);
+                self.out.push(*close);
+            }
+            Doc::Group { .. } => self.out.push_str(&doc.flat()),
+        }
+    }
+
+    fn union(&mut self, members: &[(String, Doc)], level: usize, force: bool) {
+        // A documented member of the root union is put on its own line even if it's the only member
+        let commented = members.iter().any(|(comment, _)| !comment.is_empty());
+        if (members.len() > 1 || level == 0) && commented
+            || members.len() > 1 && !self.fits(&flat_items(members, " | "))
+        {
+            for (comment, member) in members {
+                self.newline(level + 1);
+                // The comment belongs to the union member so it goes before the `|`
+                self.comment(comment, level + 1);
+                self.out.push_str("| ");
+                self.doc(member, level + 1, false);
+            }
+            return;
+        }
+
+        for (i, (comment, member)) in members.iter().enumerate() {
+            if i != 0 {
+                self.out.push_str(" | ");
+            }
+            self.comment(comment, level);
+            self.doc(member, level, force);
+        }
+    }
+
+    /// Print each line of the comment at the current indentation followed by a newline. Nothing is printed for an empty comment.
+    fn comment(&mut self, comment: &str, level: usize) {
+        if comment.is_empty() {
+            return;
+        }
+
+        for (i, line) in comment.lines().enumerate() {
+            if i != 0 {
+                self.newline(level);
+            }
+            self.out.push_str(line);
+        }
+        self.newline(level);
+    }
+}
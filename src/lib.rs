@@ -44,9 +44,11 @@
 /// Types related to working with [`crate::DataType`] directly.
 /// You'll probably never need this.
 pub mod datatype;
-/// Provides the global type store and a method to export them to other languages.
-#[cfg(feature = "export")]
+/// Export types to files in one or more languages.
+/// With the `export` feature this also provides the global type store and methods to export it.
 pub mod export;
+/// Formatters which can be run on exported files.
+pub mod formatter;
 /// Support for exporting Rust functions.
 #[cfg(feature = "functions")]
 pub mod functions;
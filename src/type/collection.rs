@@ -0,0 +1,100 @@
+use crate::*;
+
+/// A collection of types which can be exported.
+///
+/// This is an alternative to the global [`TYPES`](static@crate::export::TYPES) map populated by the `export` feature
+/// which doesn't rely on code running before `main` so it works on every target and multiple independent collections can exist.
+///
+/// ```rust
+/// use specta::{Type, TypeCollection};
+///
+/// #[derive(Type)]
+/// pub struct User {
+///     pub name: String,
+/// }
+///
+/// let mut types = TypeCollection::new();
+/// types.register::<User>();
+/// assert!(types.get("User").is_some());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct TypeCollection {
+    types: TypeDefs,
+}
+
+impl TypeCollection {
+    /// Construct a new empty `TypeCollection`
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Register `T` and every type it references.
+    /// Types which are always inlined (Eg. `String`) are not registered themselves but the types they reference are.
+    pub fn register<T: Type>(&mut self) -> &mut Self {
+        T::reference(
+            DefOpts {
+                parent_inline: false,
+                type_map: &mut self.types,
+            },
+            &[],
+        );
+        self
+    }
+
+    /// Add every type from another collection. Types which are already in this collection are replaced.
+    pub fn merge(&mut self, other: impl Into<TypeCollection>) -> &mut Self {
+        self.types.extend(other.into().types);
+        self
+    }
+
+    /// Remove a type from the collection by its name.
+    pub fn remove(&mut self, name: &str) -> Option<DataTypeExt> {
+        self.types.remove(name)
+    }
+
+    /// Get a type from the collection by its name.
+    pub fn get(&self, name: &str) -> Option<&DataTypeExt> {
+        self.types.get(name)
+    }
+
+    /// Iterate over the types in the collection, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = &DataTypeExt> {
+        self.types.values()
+    }
+
+    /// The number of types in the collection.
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns `true` if the collection doesn't contain any types.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Get the underlying [`TypeDefs`]. This can be passed to [`Language::export_all`](crate::Language::export_all).
+    pub fn as_type_defs(&self) -> &TypeDefs {
+        &self.types
+    }
+}
+
+impl From<TypeDefs> for TypeCollection {
+    fn from(types: TypeDefs) -> Self {
+        Self { types }
+    }
+}
+
+impl From<TypeCollection> for TypeDefs {
+    fn from(collection: TypeCollection) -> Self {
+        collection.types
+    }
+}
+
+impl IntoIterator for TypeCollection {
+    type Item = DataTypeExt;
+    type IntoIter = std::collections::btree_map::IntoValues<&'static str, DataTypeExt>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.types.into_values()
+    }
+}
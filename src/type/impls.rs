@@ -126,11 +126,13 @@ impl_as!(
 );
 
 use std::collections::*;
-impl_for_list!(
+impl_for_list!(List;
     Vec<T> as "Vec"
     VecDeque<T> as "VecDeque"
     BinaryHeap<T> as "BinaryHeap"
     LinkedList<T> as "LinkedList"
+);
+impl_for_list!(Set;
     HashSet<T> as "HashSet"
     BTreeSet<T> as "BTreeSet"
 );
@@ -202,7 +204,7 @@ impl<K: Type, V: Type> Flatten for std::collections::BTreeMap<K, V> {}
 
 #[cfg(feature = "indexmap")]
 const _: () = {
-    impl_for_list!(indexmap::IndexSet<T> as "IndexSet");
+    impl_for_list!(Set; indexmap::IndexSet<T> as "IndexSet");
     impl_for_map!(indexmap::IndexMap<K, V> as "IndexMap");
     impl<K: Type, V: Type> Flatten for indexmap::IndexMap<K, V> {}
 };
@@ -218,26 +220,27 @@ const _: () = {
         const IMPL_LOCATION: ImplLocation = impl_location!();
 
         fn inline(_: DefOpts, _: &[DataType]) -> DataType {
-            DataType::Any
+            DataType::WellKnown(WellKnownType::Json)
         }
     }
 };
 
 #[cfg(feature = "uuid")]
-impl_as!(
-    uuid::Uuid as String
-    uuid::fmt::Hyphenated as String
+impl_well_known!(
+    uuid::Uuid as Uuid
+    uuid::fmt::Hyphenated as Uuid
 );
 
 #[cfg(feature = "chrono")]
 const _: () = {
     use chrono::*;
 
-    impl_as!(
-        NaiveDateTime as String
-        NaiveDate as String
-        NaiveTime as String
-        chrono::Duration as String
+    impl_as!(chrono::Duration as String);
+
+    impl_well_known!(
+        NaiveDateTime as NaiveDateTime
+        NaiveDate as Date
+        NaiveTime as Time
     );
 
     impl<T: TimeZone> Type for DateTime<T> {
@@ -245,8 +248,8 @@ const _: () = {
         const SID: TypeSid = sid!();
         const IMPL_LOCATION: ImplLocation = impl_location!();
 
-        fn inline(opts: DefOpts, generics: &[DataType]) -> DataType {
-            String::inline(opts, generics)
+        fn inline(_: DefOpts, _: &[DataType]) -> DataType {
+            DataType::WellKnown(WellKnownType::DateTime)
         }
     }
 
@@ -256,26 +259,26 @@ const _: () = {
         const SID: TypeSid = sid!();
         const IMPL_LOCATION: ImplLocation = impl_location!();
 
-        fn inline(opts: DefOpts, generics: &[DataType]) -> DataType {
-            String::inline(opts, generics)
+        fn inline(_: DefOpts, _: &[DataType]) -> DataType {
+            DataType::WellKnown(WellKnownType::Date)
         }
     }
 };
 
 #[cfg(feature = "time")]
-impl_as!(
-    time::PrimitiveDateTime as String
-    time::OffsetDateTime as String
-    time::Date as String
-    time::Time as String
+impl_well_known!(
+    time::PrimitiveDateTime as NaiveDateTime
+    time::OffsetDateTime as DateTime
+    time::Date as Date
+    time::Time as Time
 );
 
 #[cfg(feature = "bigdecimal")]
-impl_as!(bigdecimal::BigDecimal as String);
+impl_well_known!(bigdecimal::BigDecimal as Decimal);
 
 // This assumes the `serde-with-str` feature is enabled. Check #26 for more info.
 #[cfg(feature = "rust_decimal")]
-impl_as!(rust_decimal::Decimal as String);
+impl_well_known!(rust_decimal::Decimal as Decimal);
 
 #[cfg(feature = "ipnetwork")]
 impl_as!(
@@ -36,7 +36,9 @@ macro_rules! impl_tuple {
                 DataType::Tuple(datatype::TupleType {
                     name: <Self as Type>::NAME,
                     fields: vec![$($i),*],
-                    generics: vec![]
+                    generics: vec![],
+                    comments: &[],
+                    deprecated: None
                 })
             }
         }
@@ -96,15 +98,36 @@ macro_rules! impl_as {
     )+};
 }
 
+#[cfg(any(
+    feature = "uuid",
+    feature = "chrono",
+    feature = "time",
+    feature = "bigdecimal",
+    feature = "rust_decimal"
+))]
+macro_rules! impl_well_known {
+    ($($ty:path as $variant:ident)+) => {$(
+        impl Type for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const SID: $crate::TypeSid = $crate::sid!();
+            const IMPL_LOCATION: $crate::ImplLocation = $crate::impl_location!();
+
+            fn inline(_: DefOpts, _: &[DataType]) -> DataType {
+                DataType::WellKnown(WellKnownType::$variant)
+            }
+        }
+    )+};
+}
+
 macro_rules! impl_for_list {
-    ($($ty:path as $name:expr)+) => {$(
+    ($variant:ident; $($ty:path as $name:expr)+) => {$(
         impl<T: Type> Type for $ty {
             const NAME: &'static str = $name;
             const SID: $crate::TypeSid = $crate::sid!();
             const IMPL_LOCATION: $crate::ImplLocation = $crate::impl_location!();
 
             fn inline(opts: DefOpts, generics: &[DataType]) -> DataType {
-                DataType::List(Box::new(generics.get(0).cloned().unwrap_or(T::inline(
+                DataType::$variant(Box::new(generics.get(0).cloned().unwrap_or(T::inline(
                     DefOpts {
                         parent_inline: false,
                         type_map: opts.type_map,
@@ -114,7 +137,7 @@ macro_rules! impl_for_list {
             }
 
             fn reference(opts: DefOpts, generics: &[DataType]) -> DataType {
-                DataType::List(Box::new(generics.get(0).cloned().unwrap_or(T::reference(
+                DataType::$variant(Box::new(generics.get(0).cloned().unwrap_or(T::reference(
                     DefOpts {
                         parent_inline: false,
                         type_map: opts.type_map,
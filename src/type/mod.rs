@@ -2,8 +2,11 @@ use crate::*;
 
 #[macro_use]
 mod macros;
+mod collection;
 mod impls;
 
+pub use collection::*;
+
 /// The category a type falls under. Determines how references are generated for a given type.
 pub enum TypeCategory {
     /// No references should be created, instead just copies the inline representation of the type.
@@ -35,6 +38,9 @@ pub trait Type {
     /// Rust documentation comments on the type
     const COMMENTS: &'static [&'static str] = &[];
 
+    /// Documentation tags on the type from `#[specta(example = "...")]`, `#[specta(since = "...")]` or `#[specta(doc_tag(...))]`
+    const DOC_TAGS: &'static [DocTag] = &[];
+
     /// The Specta ID for the type. The value for this should come from the `sid!();` macro.
     const SID: TypeSid;
 
@@ -45,8 +51,19 @@ pub trait Type {
     /// `None` will use the default which is why `false` is not just used.
     const EXPORT: Option<bool> = Some(false);
 
-    /// The Rust deprecated comment if the type is deprecated.
-    const DEPRECATED: Option<&'static str> = None;
+    /// The deprecation of the type from Rust's `#[deprecated]` attribute.
+    const DEPRECATED: Option<Deprecated> = None;
+
+    /// Whether the type has opted into being exported across an FFI boundary using `#[specta(ffi)]`.
+    const FFI: bool = false;
+
+    /// Whether a tuple struct with a single field is exported as a branded type using `#[specta(brand)]`.
+    /// This prevents it being used in place of another type with the same structure. Eg. `UserId` and `OrderId`.
+    const BRAND: bool = false;
+
+    /// Whether the properties and collections of the type are exported as immutable using `#[specta(readonly)]`.
+    /// Eg. `readonly name: string` and `readonly string[]` in Typescript.
+    const READONLY: bool = false;
 
     /// Returns the inline definition of a type with generics substituted for those provided.
     /// This function defines the base structure of every type, and is used in both
@@ -72,10 +89,14 @@ pub trait Type {
         DataTypeExt {
             name: Self::NAME,
             comments: Self::COMMENTS,
+            doc_tags: Self::DOC_TAGS,
             sid: Self::SID,
             impl_location: Self::IMPL_LOCATION,
             export: Self::EXPORT,
             deprecated: Self::DEPRECATED,
+            ffi: Self::FFI,
+            brand: Self::BRAND,
+            readonly: Self::READONLY,
             inner: Self::inline(
                 opts,
                 &Self::definition_generics()
@@ -115,35 +136,41 @@ pub trait Type {
                 placeholder,
                 reference,
             } => {
-                opts.type_map.entry(Self::NAME).or_insert(DataTypeExt {
-                    name: Self::NAME,
-                    comments: Self::COMMENTS,
-                    sid: Self::SID,
-                    impl_location: Self::IMPL_LOCATION,
-                    export: Self::EXPORT,
-                    deprecated: Self::DEPRECATED,
-                    inner: placeholder,
-                });
-
-                let definition = Self::definition(DefOpts {
-                    parent_inline: false,
-                    type_map: opts.type_map,
-                });
-
-                if let Some(ty) = opts.type_map.get(&Self::NAME) {
-                    // TODO: Properly detect duplicate name where SID don't match
-                    // println!("{:#?} {:?}", ty, definition);
-                    if matches!(ty.inner, DataType::Placeholder) {
-                        opts.type_map.insert(Self::NAME, definition);
-                    } else if ty.sid != definition.sid {
+                match opts.type_map.get(&Self::NAME) {
+                    // The type is still being defined further up the stack because it references itself
+                    Some(ty) if matches!(ty.inner, DataType::Placeholder) => {}
+                    Some(ty) if ty.sid != Self::SID => {
                         // TODO: Return runtime error instead of panicking
                         #[allow(clippy::panic)]
                         {
-                            panic!("Specta: you have tried to export two types both called '{}' declared at '{}' and '{}'! You could give both types a unique name or put `#[specta(inline)]` on one/both of them to cause it to be exported without a name.", ty.name, ty.impl_location.as_str(), definition.impl_location.as_str());
+                            panic!("Specta: you have tried to export two types both called '{}' declared at '{}' and '{}'! You could give both types a unique name or put `#[specta(inline)]` on one/both of them to cause it to be exported without a name.", ty.name, ty.impl_location.as_str(), Self::IMPL_LOCATION.as_str());
                         }
                     }
-                } else {
-                    opts.type_map.insert(Self::NAME, definition);
+                    Some(_) => {}
+                    None => {
+                        opts.type_map.insert(
+                            Self::NAME,
+                            DataTypeExt {
+                                name: Self::NAME,
+                                comments: Self::COMMENTS,
+                                doc_tags: Self::DOC_TAGS,
+                                sid: Self::SID,
+                                impl_location: Self::IMPL_LOCATION,
+                                export: Self::EXPORT,
+                                deprecated: Self::DEPRECATED,
+                                ffi: Self::FFI,
+                                brand: Self::BRAND,
+                                readonly: Self::READONLY,
+                                inner: placeholder,
+                            },
+                        );
+
+                        let definition = Self::definition(DefOpts {
+                            parent_inline: false,
+                            type_map: opts.type_map,
+                        });
+                        opts.type_map.insert(Self::NAME, definition);
+                    }
                 }
 
                 reference
@@ -0,0 +1,142 @@
+use specta::{
+    c::{self, CExportError, C},
+    Language, Type, TypeCollection,
+};
+
+#[derive(Type)]
+#[specta(export = false, ffi)]
+#[repr(C)]
+struct CPoint {
+    x: i32,
+    y: f64,
+    visible: bool,
+}
+
+#[derive(Type)]
+#[specta(export = false, ffi)]
+#[repr(C)]
+struct CPair(u8, CPoint);
+
+#[derive(Type)]
+#[specta(export = false, ffi)]
+#[repr(C)]
+enum CColor {
+    Red,
+    Green,
+}
+
+#[derive(Type)]
+#[specta(export = false, ffi)]
+#[repr(C)]
+enum CShape {
+    Circle(f32),
+    Rect { w: u16, h: u16 },
+    Empty,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[repr(C)]
+struct CNotMarked {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false, ffi)]
+#[repr(C)]
+struct CHeap {
+    name: String,
+}
+
+#[test]
+fn c_declarations() {
+    assert_eq!(
+        c::export::<CPoint>().unwrap(),
+        "typedef struct CPoint {\n    int32_t x;\n    double y;\n    bool visible;\n} CPoint;"
+    );
+    assert_eq!(
+        c::export::<CPair>().unwrap(),
+        "typedef struct CPair {\n    uint8_t _0;\n    CPoint _1;\n} CPair;"
+    );
+    assert_eq!(
+        c::export::<CColor>().unwrap(),
+        "typedef enum CColor {\n    CColor_Red,\n    CColor_Green,\n} CColor;"
+    );
+    assert_eq!(
+        c::export::<CShape>().unwrap(),
+        "typedef enum CShape_Tag {\n    CShape_Circle,\n    CShape_Rect,\n    CShape_Empty,\n} CShape_Tag;\n\ntypedef struct CShape_Circle_Body {\n    float _0;\n} CShape_Circle_Body;\n\ntypedef struct CShape_Rect_Body {\n    uint16_t w;\n    uint16_t h;\n} CShape_Rect_Body;\n\ntypedef struct CShape {\n    CShape_Tag tag;\n    union {\n        CShape_Circle_Body circle;\n        CShape_Rect_Body rect;\n    };\n} CShape;"
+    );
+
+    assert!(matches!(
+        c::export::<CNotMarked>(),
+        Err(CExportError::NotFfi("CNotMarked"))
+    ));
+    match c::export::<CHeap>() {
+        Err(CExportError::WithCtx { err, .. }) => match *err {
+            CExportError::WithCtx { err, .. } => {
+                assert!(matches!(*err, CExportError::HeapType("String")))
+            }
+            err => panic!("unexpected error {err:?}"),
+        },
+        v => panic!("unexpected result {v:?}"),
+    }
+}
+
+#[derive(Type)]
+#[specta(export = false, ffi)]
+#[repr(C)]
+struct CRefersToNotMarked {
+    a: CNotMarked,
+}
+
+#[test]
+fn c_references() {
+    match c::export::<CRefersToNotMarked>() {
+        Err(CExportError::WithCtx { err, .. }) => match *err {
+            CExportError::WithCtx { err, .. } => {
+                assert!(matches!(*err, CExportError::NotFfi("CNotMarked")))
+            }
+            err => panic!("unexpected error {err:?}"),
+        },
+        v => panic!("unexpected result {v:?}"),
+    }
+
+    // Referenced types must be exported alongside the types which reference them
+    let mut types = TypeCollection::new();
+    types.register::<CPair>();
+    types.remove("CPoint");
+    assert!(C::export_all(&(), types.as_type_defs()).is_err());
+}
+
+#[test]
+fn c_language_skips_types_without_ffi() {
+    let mut types = TypeCollection::new();
+    types
+        .register::<CPair>()
+        .register::<CColor>()
+        .register::<CNotMarked>();
+
+    assert_eq!(
+        C::export_all(&(), types.as_type_defs()).unwrap(),
+        "typedef enum CColor {\n    CColor_Red,\n    CColor_Green,\n} CColor;\n\ntypedef struct CPoint {\n    int32_t x;\n    double y;\n    bool visible;\n} CPoint;\n\ntypedef struct CPair {\n    uint8_t _0;\n    CPoint _1;\n} CPair;"
+    );
+}
+
+#[derive(Type)]
+#[specta(export = false, ffi)]
+#[repr(C)]
+struct CNode {
+    value: i32,
+    next: Box<CNode>,
+}
+
+#[test]
+fn c_recursive_types() {
+    let mut types = TypeCollection::new();
+    types.register::<CNode>();
+
+    assert!(matches!(
+        C::export_all(&(), types.as_type_defs()),
+        Err(CExportError::RecursiveType(group)) if group == ["CNode"]
+    ));
+}
@@ -0,0 +1,255 @@
+use specta::{
+    ts::{CommentContext, CommentKind, ExportConfiguration, PrettyConfig},
+    DataType, DefOpts, DocTag, EnumVariant, Type,
+};
+
+use crate::ts::assert_ts_export;
+
+/// A user of the system
+#[derive(Type)]
+#[specta(export = false)]
+struct CommentedUser {
+    /// The unique id
+    id: i32,
+    /// The display name.
+    /// Shown to other users.
+    name: String,
+    tags: Vec<String>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+enum CommentedEvent {
+    /// The user was created
+    Created {
+        /// The id of the new user
+        id: i32,
+    },
+    /// The user was deleted
+    Deleted(i32),
+    Unknown,
+}
+
+#[derive(Type)]
+#[specta(export = false, tag = "type")]
+enum CommentedInternal {
+    /// A circle
+    Circle {
+        /// The radius
+        radius: f64,
+    },
+}
+
+/// A product for sale
+#[derive(Type)]
+#[specta(
+    export = false,
+    since = "1.4",
+    doc_tag(name = "see", value = "https://example.com/products")
+)]
+struct TaggedProduct {
+    /// The price in cents
+    #[specta(example = "1999")]
+    price: i32,
+    #[specta(example = "{\n  \"colour\": \"red\"\n}")]
+    attributes: String,
+}
+
+#[test]
+fn comments_are_stored_in_the_datatype() {
+    let typ = CommentedUser::inline(
+        DefOpts {
+            parent_inline: true,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    );
+    let DataType::Object(obj) = typ else {
+        panic!("expected an object");
+    };
+    assert_eq!(obj.comments, [" A user of the system"]);
+    assert_eq!(obj.fields[0].comments, [" The unique id"]);
+    assert_eq!(
+        obj.fields[1].comments,
+        [" The display name.", " Shown to other users."]
+    );
+    assert!(obj.fields[2].comments.is_empty());
+
+    let typ = CommentedEvent::inline(
+        DefOpts {
+            parent_inline: true,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    );
+    let DataType::Enum(e) = typ else {
+        panic!("expected an enum");
+    };
+    assert_eq!(e.variants[0].comments(), [" The user was created"]);
+    assert_eq!(e.variants[1].comments(), [" The user was deleted"]);
+    assert!(matches!(
+        e.variants[2],
+        EnumVariant::Unit("Unknown", [], None)
+    ));
+    let EnumVariant::Named(obj) = &e.variants[0] else {
+        panic!("expected a named variant");
+    };
+    assert_eq!(obj.fields[0].comments, [" The id of the new user"]);
+}
+
+#[test]
+fn typescript_comments() {
+    let conf = ExportConfiguration::new().pretty(Some(PrettyConfig::default()));
+
+    assert_ts_export!(
+        CommentedUser,
+        "/**\n *  A user of the system\n */\nexport type CommentedUser = {\n  /**\n   *  The unique id\n   */\n  id: number;\n  /**\n   *  The display name.\n   *  Shown to other users.\n   */\n  name: string;\n  tags: string[];\n}";
+        &conf
+    );
+    assert_ts_export!(
+        CommentedEvent,
+        "export type CommentedEvent =\n  /**\n   *  The user was created\n   */\n  | {\n    Created: {\n      /**\n       *  The id of the new user\n       */\n      id: number;\n    };\n  }\n  /**\n   *  The user was deleted\n   */\n  | { Deleted: number }\n  | \"Unknown\"";
+        &conf
+    );
+    assert_ts_export!(
+        CommentedInternal,
+        "export type CommentedInternal =\n  /**\n   *  A circle\n   */\n  | {\n    type: \"Circle\";\n    /**\n     *  The radius\n     */\n    radius: number;\n  }";
+        &conf
+    );
+
+    // Comments are only rendered for exported declarations as inline types are used in places where newlines aren't expected
+    assert_eq!(
+        specta::ts::inline::<CommentedUser>(&Default::default()).unwrap(),
+        "{ id: number; name: string; tags: string[] }"
+    );
+
+    // Without a comment exporter the single line layout is kept
+    assert_ts_export!(
+        CommentedUser,
+        "export type CommentedUser = { id: number; name: string; tags: string[] }";
+        &ExportConfiguration::new().comment_style(None)
+    );
+}
+
+#[test]
+fn typescript_comments_default_config() {
+    // Field and variant comments must be placed on their own lines so they switch the type to the multiline layout
+    assert_ts_export!(
+        CommentedUser,
+        "/**\n *  A user of the system\n */\nexport type CommentedUser = {\n  /**\n   *  The unique id\n   */\n  id: number;\n  /**\n   *  The display name.\n   *  Shown to other users.\n   */\n  name: string;\n  tags: string[];\n}"
+    );
+    assert_ts_export!(
+        CommentedEvent,
+        "export type CommentedEvent =\n  /**\n   *  The user was created\n   */\n  | {\n    Created: {\n      /**\n       *  The id of the new user\n       */\n      id: number;\n    };\n  }\n  /**\n   *  The user was deleted\n   */\n  | { Deleted: number }\n  | \"Unknown\""
+    );
+    assert_ts_export!(
+        TaggedProduct,
+        "/**\n *  A product for sale\n * @since 1.4\n * @see https://example.com/products\n */\nexport type TaggedProduct = {\n  /**\n   *  The price in cents\n   * @example 1999\n   */\n  price: number;\n  /**\n   * @example {\n   *   \"colour\": \"red\"\n   * }\n   */\n  attributes: string;\n}"
+    );
+}
+
+#[test]
+fn doc_tags() {
+    assert_eq!(
+        TaggedProduct::DOC_TAGS,
+        [
+            DocTag {
+                name: "since",
+                value: "1.4"
+            },
+            DocTag {
+                name: "see",
+                value: "https://example.com/products"
+            }
+        ]
+    );
+
+    let DataType::Object(obj) = TaggedProduct::inline(
+        DefOpts {
+            parent_inline: true,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    ) else {
+        panic!("expected an object");
+    };
+    assert_eq!(
+        obj.fields[0].doc_tags,
+        [DocTag {
+            name: DocTag::EXAMPLE,
+            value: "1999"
+        }]
+    );
+
+    assert_ts_export!(
+        TaggedProduct,
+        "/**\n *  A product for sale\n * @since 1.4\n * @see https://example.com/products\n */\nexport type TaggedProduct = {\n  /**\n   *  The price in cents\n   * @example 1999\n   */\n  price: number;\n  /**\n   * @example {\n   *   \"colour\": \"red\"\n   * }\n   */\n  attributes: string;\n}";
+        &ExportConfiguration::new().pretty(Some(PrettyConfig::default()))
+    );
+}
+
+/// An account
+/// internal: stored in the accounts table
+#[derive(Type)]
+#[specta(export = false)]
+struct FormattedAccount {
+    /// The balance in cents
+    /// internal: may be negative
+    #[deprecated = "Use ledger instead"]
+    balance: i32,
+}
+
+#[test]
+fn comment_formatter_context() {
+    // Closures can be used to strip lines which shouldn't leave the backend
+    let conf = ExportConfiguration::new()
+        .pretty(Some(PrettyConfig::default()))
+        .comment_formatter(|ctx: &CommentContext| {
+            let lines = ctx
+                .lines()
+                .into_iter()
+                .filter(|line| !line.contains("internal:"))
+                .collect::<Vec<_>>();
+            specta::ts::comments::js_doc(&CommentContext {
+                comments: &lines.iter().map(String::as_str).collect::<Vec<_>>(),
+                doc_tags: &[],
+                deprecated: None,
+                ..ctx.clone()
+            })
+        });
+    assert_ts_export!(
+        FormattedAccount,
+        "/**\n *  An account\n */\nexport type FormattedAccount = {\n  /**\n   *  The balance in cents\n   * @deprecated Use ledger instead\n   */\n  balance: number;\n}";
+        &conf
+    );
+
+    // The formatter knows what it's documenting
+    let conf = ExportConfiguration::new()
+        .pretty(Some(PrettyConfig::default()))
+        .comment_formatter(|ctx: &CommentContext| {
+            let kind = match ctx.kind {
+                CommentKind::Type => "type",
+                CommentKind::Field => "field",
+                CommentKind::Variant => "variant",
+            };
+            format!(
+                "// {kind} {} at depth {}{}\n",
+                ctx.name,
+                ctx.depth,
+                match ctx.deprecated {
+                    Some(_) => " is deprecated",
+                    None => "",
+                }
+            )
+        });
+    assert_ts_export!(
+        FormattedAccount,
+        "// type FormattedAccount at depth 0\nexport type FormattedAccount = {\n  // field balance at depth 1 is deprecated\n  balance: number;\n}";
+        &conf
+    );
+    assert_ts_export!(
+        CommentedEvent,
+        "// type CommentedEvent at depth 0\nexport type CommentedEvent =\n  // variant Created at depth 1\n  | {\n    Created: {\n      // field id at depth 2\n      id: number;\n    };\n  }\n  // variant Deleted at depth 1\n  | { Deleted: number }\n  // variant Unknown at depth 1\n  | \"Unknown\"";
+        &conf
+    );
+}
@@ -0,0 +1,109 @@
+use specta::{
+    ts::{ExportConfiguration, PrettyConfig},
+    Constraints, DataType, DefOpts, Type,
+};
+use validator::Validate;
+
+use crate::ts::assert_ts_export;
+
+const MAX_LEN: u64 = 500;
+
+#[derive(Type)]
+#[specta(export = false)]
+struct ConstrainedUser {
+    /// The display name
+    #[specta(min_length = 1, max_length = 64, pattern = "^[a-z]+$")]
+    name: String,
+    #[specta(min = -10, max = 99.5)]
+    score: f64,
+    tags: Vec<String>,
+}
+
+#[derive(Type, Validate)]
+#[specta(export = false)]
+struct ValidatedUser {
+    #[validate(length(min = 1, max = 64))]
+    name: String,
+    #[validate(length(equal = 2), email)]
+    code: String,
+    #[validate(range(min = 18, max = 150))]
+    age: i32,
+    // Rules which can't be evaluated at compile time are skipped
+    #[validate(length(max = "MAX_LEN"))]
+    bio: String,
+}
+
+fn fields<T: Type>() -> Vec<Constraints> {
+    let DataType::Object(obj) = T::inline(
+        DefOpts {
+            parent_inline: true,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    ) else {
+        panic!("expected an object");
+    };
+
+    obj.fields.into_iter().map(|f| f.constraints).collect()
+}
+
+#[test]
+fn constraints_are_stored_in_the_datatype() {
+    assert_eq!(
+        fields::<ConstrainedUser>(),
+        [
+            Constraints {
+                min_length: Some(1),
+                max_length: Some(64),
+                pattern: Some("^[a-z]+$"),
+                ..Default::default()
+            },
+            Constraints {
+                min: Some(-10.0),
+                max: Some(99.5),
+                ..Default::default()
+            },
+            Constraints::default()
+        ]
+    );
+
+    assert_eq!(
+        fields::<ValidatedUser>(),
+        [
+            Constraints {
+                min_length: Some(1),
+                max_length: Some(64),
+                ..Default::default()
+            },
+            Constraints {
+                min_length: Some(2),
+                max_length: Some(2),
+                ..Default::default()
+            },
+            Constraints {
+                min: Some(18.0),
+                max: Some(150.0),
+                ..Default::default()
+            },
+            Constraints::default()
+        ]
+    );
+}
+
+#[test]
+fn typescript_constraints() {
+    assert_ts_export!(
+        ConstrainedUser,
+        "export type ConstrainedUser = {\n  /**\n   *  The display name\n   * @minLength 1\n   * @maxLength 64\n   * @pattern ^[a-z]+$\n   */\n  name: string;\n  /**\n   * @minimum -10\n   * @maximum 99.5\n   */\n  score: number;\n  tags: string[];\n}";
+        &ExportConfiguration::new().pretty(Some(PrettyConfig::default()))
+    );
+}
+
+#[cfg(feature = "jsdoc")]
+#[test]
+fn jsdoc_constraints() {
+    assert_eq!(
+        specta::jsdoc::export::<ConstrainedUser>(&Default::default()).unwrap(),
+        "/**\n * @typedef {Object} ConstrainedUser\n * @property {string} name - The display name (minLength 1, maxLength 64, pattern ^[a-z]+$)\n * @property {number} score - (minimum -10, maximum 99.5)\n * @property {string[]} tags\n */"
+    );
+}
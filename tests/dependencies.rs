@@ -0,0 +1,89 @@
+use specta::{cycles, dependency_order, reachable, DefOpts, Type, TypeDefs};
+
+#[derive(Type)]
+#[specta(export = false)]
+struct DepA {
+    b: DepB,
+    c: Vec<DepC>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct DepB {
+    c: Option<DepC>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct DepC {
+    value: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct DepCycleX {
+    y: Option<Box<DepCycleY>>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+enum DepCycleY {
+    X(DepCycleX),
+    None,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct DepSelf {
+    children: Vec<DepSelf>,
+}
+
+fn insert<T: Type>(types: &mut TypeDefs) {
+    let def = T::definition(DefOpts {
+        parent_inline: false,
+        type_map: types,
+    });
+    types.insert(def.name, def);
+}
+
+#[test]
+fn test_dependency_order() {
+    let mut types = TypeDefs::default();
+    insert::<DepA>(&mut types);
+    insert::<DepCycleX>(&mut types);
+    insert::<DepSelf>(&mut types);
+
+    assert_eq!(
+        dependency_order(&types),
+        vec![
+            vec!["DepC"],
+            vec!["DepB"],
+            vec!["DepA"],
+            vec!["DepCycleX", "DepCycleY"],
+            vec!["DepSelf"],
+        ]
+    );
+    assert_eq!(
+        cycles(&types),
+        vec![vec!["DepCycleX", "DepCycleY"], vec!["DepSelf"]]
+    );
+}
+
+#[test]
+fn test_reachable() {
+    let mut types = TypeDefs::default();
+    insert::<DepA>(&mut types);
+    insert::<DepCycleX>(&mut types);
+    insert::<DepSelf>(&mut types);
+
+    assert_eq!(
+        reachable(&types, ["DepB"]).keys().collect::<Vec<_>>(),
+        vec![&"DepB", &"DepC"]
+    );
+    assert_eq!(
+        reachable(&types, ["DepCycleY", "DepSelf", "Missing"])
+            .keys()
+            .collect::<Vec<_>>(),
+        vec![&"DepCycleX", &"DepCycleY", &"DepSelf"]
+    );
+}
@@ -0,0 +1,124 @@
+use specta::{
+    elm::{self, BigIntExportBehavior, ElmExportError, ExportConfiguration},
+    Type,
+};
+
+#[derive(Type)]
+#[specta(export = false)]
+struct ElmUser {
+    name: String,
+    age: Option<i32>,
+    #[specta(optional)]
+    nickname: Option<String>,
+    tags: Vec<(String, bool)>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct ElmGeneric<T> {
+    value: T,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+enum ElmExternal {
+    A,
+    B(i32),
+    C(i32, String),
+    D { a: ElmUser },
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(tag = "type")]
+enum ElmInternal {
+    A,
+    B { a: i32 },
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(tag = "t", content = "c")]
+enum ElmAdjacent {
+    A,
+    B(String),
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(untagged)]
+enum ElmUntagged {
+    A,
+    B(String),
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct ElmBigInt {
+    a: i128,
+}
+
+#[test]
+fn elm_export() {
+    let conf = ExportConfiguration::new();
+
+    assert_eq!(
+        elm::inline::<Vec<Option<i32>>>(&conf).unwrap(),
+        "List (Maybe Int)"
+    );
+    assert_eq!(
+        elm::export::<ElmGeneric<()>>(&conf).unwrap(),
+        "type alias ElmGeneric t =\n    { value : t\n    }\n\n\ndecodeElmGeneric : D.Decoder t -> D.Decoder (ElmGeneric t)\ndecodeElmGeneric decodeT =\n    D.succeed (\\a0 -> { value = a0 })\n        |> D.map2 (|>) (D.field \"value\" decodeT)\n\n\nencodeElmGeneric : (t -> E.Value) -> (ElmGeneric t) -> E.Value\nencodeElmGeneric encodeT value =\n    E.object [ ( \"value\", encodeT value.value ) ]"
+    );
+
+    let user = elm::export::<ElmUser>(&conf).unwrap();
+    assert!(user.contains("    , nickname : Maybe String\n"));
+    assert!(user.contains(
+        "(D.map (Maybe.andThen identity) (D.maybe (D.field \"nickname\" (D.nullable D.string))))"
+    ));
+    assert!(user.contains("Maybe.map (\\o1 -> ( \"nickname\", E.string o1 )) value.nickname"));
+
+    let external = elm::export::<ElmExternal>(&conf).unwrap();
+    assert!(external.starts_with("type ElmExternal\n    = ElmExternalA\n    | ElmExternalB Int\n    | ElmExternalC Int String\n    | ElmExternalD { a : ElmUser }\n"));
+    assert!(external.contains(", D.field \"C\" (D.succeed ElmExternalC |> D.map2 (|>) (D.index 0 D.int) |> D.map2 (|>) (D.index 1 D.string))\n"));
+    assert!(external.contains("ElmExternalC p0 p1 ->\n            E.object [ ( \"C\", E.list identity [ E.int p0, E.string p1 ] ) ]"));
+
+    let internal = elm::export::<ElmInternal>(&conf).unwrap();
+    assert!(internal.contains("D.field \"type\" D.string\n        |> D.andThen\n"));
+    assert!(internal
+        .contains("E.object ([ ( \"type\", E.string \"B\" ) ] ++ [ ( \"a\", E.int p.a ) ])"));
+
+    let adjacent = elm::export::<ElmAdjacent>(&conf).unwrap();
+    assert!(adjacent
+        .contains("\"B\" ->\n                        D.field \"c\" (D.map ElmAdjacentB D.string)"));
+    assert!(adjacent.contains("E.object [ ( \"t\", E.string \"B\" ), ( \"c\", E.string p0 ) ]"));
+
+    let untagged = elm::export::<ElmUntagged>(&conf).unwrap();
+    assert!(untagged
+        .contains("[ D.null ElmUntaggedA\n        , D.map ElmUntaggedB D.string\n        ]"));
+
+    assert!(matches!(
+        elm::export::<ElmBigInt>(&conf),
+        Err(ElmExportError::WithCtx { .. })
+    ));
+}
+
+#[test]
+fn elm_bigint() {
+    assert!(matches!(
+        elm::inline::<u64>(&ExportConfiguration::new()),
+        Err(ElmExportError::BigIntForbidden)
+    ));
+
+    let conf = ExportConfiguration::new().bigint(BigIntExportBehavior::Int);
+    assert_eq!(elm::inline::<u64>(&conf).unwrap(), "Int");
+    assert!(elm::export::<ElmBigInt>(&conf)
+        .unwrap()
+        .contains("D.field \"a\" D.int"));
+
+    let conf = ExportConfiguration::new().bigint(BigIntExportBehavior::String);
+    let export = elm::export::<ElmBigInt>(&conf).unwrap();
+    assert!(export.contains("{ a : String\n"));
+    assert!(export.contains("D.field \"a\" D.string"));
+    assert!(export.contains("( \"a\", E.string value.a )"));
+}
@@ -46,3 +46,193 @@ fn test_export_feature() {
     assert_eq!(fs::read_to_string("./bindings2.ts").unwrap(), "// This file has been generated by Specta. DO NOT EDIT.\n\nexport type DAffine2 = { matrix2: DMat2; translation: DVec2 }\n\nexport type DMat2 = { x_axis: DVec2; y_axis: DVec2 }\n\nexport type DVec2 = { x: number; y: number }\n\nexport type IVec2 = { x: number; y: number }\n\nexport type ReferingToUnexportedType = { a: NotExported }\n\nexport type Timestamp = { time: number; id: number }\n\nexport type TypeOne = { field1: string; field2: TypeTwo }\n\nexport type TypeTwo = { my_field: string }\n\n");
     fs::remove_file("./bindings2.ts").unwrap();
 }
+
+#[cfg(feature = "export")]
+#[test]
+fn test_export_multiple_languages() {
+    use specta::{
+        export::Export,
+        ts::{BigIntExportBehavior, ExportConfiguration, Typescript},
+    };
+    use std::fs;
+
+    Export::new()
+        .language::<Typescript>(
+            ExportConfiguration::default().bigint(BigIntExportBehavior::Number),
+            "./bindings3.ts",
+        )
+        .language::<Typescript>(
+            ExportConfiguration::default()
+                .bigint(BigIntExportBehavior::Number)
+                .comment_style(None),
+            "./bindings4.ts",
+        )
+        .write()
+        .unwrap();
+
+    for path in ["./bindings3.ts", "./bindings4.ts"] {
+        let out = fs::read_to_string(path).unwrap();
+        assert!(out.contains("export type TypeOne = { field1: string; field2: TypeTwo }"));
+        assert!(!out.contains("export type NotExported"));
+        fs::remove_file(path).unwrap();
+    }
+}
+
+#[cfg(all(feature = "export", feature = "elm", feature = "php", feature = "c"))]
+#[test]
+fn test_export_language_headers() {
+    use specta::{
+        c::{self, C},
+        elm::{self, BigIntExportBehavior, Elm},
+        export::Export,
+        php::{self, Php},
+    };
+    use std::fs;
+
+    Export::new()
+        .language::<Elm>(
+            elm::ExportConfiguration::default()
+                .bigint(BigIntExportBehavior::Int)
+                .module("Bindings"),
+            "./bindings_headers.elm",
+        )
+        .language::<Php>(
+            php::ExportConfiguration::new().bigint(php::BigIntExportBehavior::String),
+            "./bindings_headers.php",
+        )
+        .language::<C>((), "./bindings_headers.h")
+        .write()
+        .unwrap();
+
+    for (path, header) in [
+        ("./bindings_headers.elm", elm::module_header("Bindings")),
+        ("./bindings_headers.php", "<?php".into()),
+        ("./bindings_headers.h", c::INCLUDES.into()),
+    ] {
+        let out = fs::read_to_string(path).unwrap();
+        fs::remove_file(path).unwrap();
+        assert!(out.starts_with(&header), "{path}: {out}");
+    }
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_export_check() {
+    use specta::{
+        export::{write_if_changed, Export, ExportError},
+        ts::{BigIntExportBehavior, ExportConfiguration, Typescript},
+    };
+    use std::fs;
+
+    let export = Export::new().language::<Typescript>(
+        ExportConfiguration::default().bigint(BigIntExportBehavior::Number),
+        "./bindings5.ts",
+    );
+
+    match export.check() {
+        Err(ExportError::Outdated { diff, .. }) => assert!(diff.removed.is_empty()),
+        v => panic!("unexpected result {v:?}"),
+    }
+
+    export.write().unwrap();
+    export.check().unwrap();
+
+    let out = fs::read_to_string("./bindings5.ts").unwrap();
+    assert!(!write_if_changed("./bindings5.ts", &out).unwrap());
+
+    fs::write(
+        "./bindings5.ts",
+        out.replace("field1: string", "field1: number"),
+    )
+    .unwrap();
+    match export.check() {
+        Err(ExportError::Outdated { diff, .. }) => {
+            assert_eq!(
+                diff.added,
+                vec!["export type TypeOne = { field1: string; field2: TypeTwo }"]
+            );
+            assert_eq!(
+                diff.removed,
+                vec!["export type TypeOne = { field1: number; field2: TypeTwo }"]
+            );
+        }
+        v => panic!("unexpected result {v:?}"),
+    }
+
+    // Reordered lines are reported as removed from their old position and added at their new one
+    let type_one = "export type TypeOne = { field1: string; field2: TypeTwo }";
+    let type_two = "export type TypeTwo = { my_field: string }";
+    fs::write(
+        "./bindings5.ts",
+        out.replace(type_one, "TYPE_ONE")
+            .replace(type_two, type_one)
+            .replace("TYPE_ONE", type_two),
+    )
+    .unwrap();
+    match export.check() {
+        Err(ExportError::Outdated { diff, .. }) => {
+            assert!(!diff.added.is_empty());
+            assert_eq!(diff.added.len(), diff.removed.len());
+        }
+        v => panic!("unexpected result {v:?}"),
+    }
+    fs::remove_file("./bindings5.ts").unwrap();
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_export_roots() {
+    use specta::{
+        export::Export,
+        ts::{ExportConfiguration, Typescript},
+    };
+    use std::fs;
+
+    Export::new()
+        .language::<Typescript>(ExportConfiguration::default(), "./bindings6.ts")
+        .root::<ReferingToUnexportedType>()
+        .root::<TypeTwo>()
+        .write()
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string("./bindings6.ts").unwrap(),
+        "// This file has been generated by Specta. DO NOT EDIT.\n\nexport type NotExported = { b: number }\n\nexport type ReferingToUnexportedType = { a: NotExported }\n\nexport type TypeTwo = { my_field: string }"
+    );
+    fs::remove_file("./bindings6.ts").unwrap();
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_export_ts_check() {
+    use specta::{
+        export::{self, ExportError},
+        ts::{BigIntExportBehavior, ExportConfiguration},
+    };
+    use std::fs;
+
+    let cfg = ExportConfiguration::default().bigint(BigIntExportBehavior::Number);
+
+    match export::ts_check_with_cfg(&cfg, "./bindings11.ts") {
+        Err(ExportError::Outdated { diff, .. }) => assert!(diff.removed.is_empty()),
+        v => panic!("unexpected result {v:?}"),
+    }
+
+    export::ts_with_cfg(&cfg, "./bindings11.ts").unwrap();
+    export::ts_check_with_cfg(&cfg, "./bindings11.ts").unwrap();
+
+    let out = fs::read_to_string("./bindings11.ts").unwrap();
+    fs::write(
+        "./bindings11.ts",
+        out.replace("my_field: string", "my_field: number"),
+    )
+    .unwrap();
+    match export::ts_check_with_cfg(&cfg, "./bindings11.ts") {
+        Err(ExportError::Outdated { diff, .. }) => assert_eq!(
+            diff.added,
+            vec!["export type TypeTwo = { my_field: string }"]
+        ),
+        v => panic!("unexpected result {v:?}"),
+    }
+    fs::remove_file("./bindings11.ts").unwrap();
+}
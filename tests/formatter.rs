@@ -0,0 +1,63 @@
+use std::path::Path;
+
+use specta::{
+    export::{Export, ExportError, Output},
+    formatter,
+    ts::{ExportConfiguration, Typescript},
+    Type, TypeCollection,
+};
+
+#[derive(Type)]
+#[specta(export = false)]
+struct FormatterDemo {
+    a: i32,
+}
+
+#[test]
+fn test_rustfmt() {
+    if std::process::Command::new("rustfmt")
+        .arg("--version")
+        .output()
+        .is_err()
+    {
+        eprintln!("Skipping `test_rustfmt` as `rustfmt` isn't installed");
+        return;
+    }
+
+    assert_eq!(
+        formatter::rustfmt(Path::new("demo.rs"), "struct  Demo{a:i32}").unwrap(),
+        "struct Demo {\n    a: i32,\n}\n"
+    );
+    assert!(formatter::rustfmt(Path::new("demo.rs"), "struct {").is_err());
+}
+
+#[test]
+fn test_export_formatter() {
+    let mut types = TypeCollection::new();
+    types.register::<FormatterDemo>();
+
+    Export::new()
+        .types(types.clone())
+        .language::<Typescript>(
+            ExportConfiguration::default(),
+            Output::new("./bindings9.ts").formatter(|_, contents| Ok(format!("{contents};\n"))),
+        )
+        .write()
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string("./bindings9.ts").unwrap(),
+        "// This file has been generated by Specta. DO NOT EDIT.\n\nexport type FormatterDemo = { a: number };\n"
+    );
+    std::fs::remove_file("./bindings9.ts").unwrap();
+
+    let result = Export::new()
+        .types(types)
+        .language::<Typescript>(
+            ExportConfiguration::default(),
+            Output::new("./bindings10.ts")
+                .formatter(|path, contents| formatter::rustfmt(path, &format!("{contents} {{"))),
+        )
+        .write();
+    assert!(matches!(result, Err(ExportError::Format { .. })));
+    assert!(!Path::new("./bindings10.ts").exists());
+}
@@ -0,0 +1,176 @@
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+use specta::{
+    java::{self, ExportConfiguration, JavaExportError, NullableBehavior},
+    Type,
+};
+
+/// A user of the system
+#[derive(Type)]
+#[specta(export = false)]
+struct JavaUser {
+    id: i32,
+    name: String,
+    tags: Vec<String>,
+    scores: HashMap<String, f64>,
+    nickname: Option<String>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct JavaPage<T> {
+    items: Vec<T>,
+    total: u64,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct JavaUserId(u32);
+
+#[derive(Type)]
+#[specta(export = false)]
+struct JavaTagged {
+    tags: BTreeSet<String>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct JavaPair(i8, bool);
+
+#[derive(Type)]
+#[specta(export = false)]
+enum JavaStatus {
+    Active,
+    Disabled,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[specta(export = false)]
+enum JavaExternal {
+    Circle(f32),
+    Rect { w: u16, h: u16 },
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[specta(export = false)]
+#[serde(tag = "type")]
+enum JavaInternal {
+    Click { x: i32, y: i32 },
+    Close,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[specta(export = false)]
+#[serde(tag = "t", content = "c")]
+enum JavaAdjacent {
+    Text(String),
+    Move { x: i32 },
+    Empty,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[specta(export = false)]
+enum JavaMixed {
+    Empty,
+    Full(i32),
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[deprecated(since = "1.0.0", note = "Use JavaUser")]
+struct JavaLegacyUser {
+    #[deprecated]
+    id: i32,
+}
+
+#[test]
+fn java_export() {
+    let conf = ExportConfiguration::default();
+
+    assert_eq!(
+        java::export::<JavaUser>(&conf).unwrap(),
+        "/**\n *  A user of the system\n */\npublic record JavaUser(\n    @JsonProperty(\"id\") Integer id,\n    @JsonProperty(\"name\") String name,\n    @JsonProperty(\"tags\") List<String> tags,\n    @JsonProperty(\"scores\") Map<String, Double> scores,\n    @JsonProperty(\"nickname\") @Nullable String nickname\n) {\n}"
+    );
+    assert_eq!(
+        java::export::<JavaUser>(&ExportConfiguration::new().nullable(NullableBehavior::Optional))
+            .unwrap()
+            .lines()
+            .find(|l| l.contains("nickname"))
+            .unwrap(),
+        "    @JsonProperty(\"nickname\") Optional<String> nickname"
+    );
+    assert_eq!(
+        java::export::<JavaPage<()>>(&conf).unwrap(),
+        "public record JavaPage<T>(\n    @JsonProperty(\"items\") List<T> items,\n    @JsonProperty(\"total\") BigInteger total\n) {\n}"
+    );
+    assert_eq!(
+        java::export::<JavaUserId>(&conf).unwrap(),
+        "public record JavaUserId(\n    @JsonValue Long value\n) {\n    @JsonCreator(mode = JsonCreator.Mode.DELEGATING)\n    public JavaUserId {\n    }\n}"
+    );
+    assert_eq!(
+        java::export::<JavaTagged>(&conf).unwrap(),
+        "public record JavaTagged(\n    @JsonProperty(\"tags\") Set<String> tags\n) {\n}"
+    );
+    assert_eq!(
+        java::export::<JavaPair>(&conf).unwrap(),
+        "@JsonFormat(shape = JsonFormat.Shape.ARRAY)\n@JsonPropertyOrder({\"_0\", \"_1\"})\npublic record JavaPair(\n    Byte _0,\n    Boolean _1\n) {\n}"
+    );
+    assert_eq!(
+        java::export::<JavaStatus>(&conf).unwrap(),
+        "public enum JavaStatus {\n    @JsonProperty(\"Active\") Active,\n    @JsonProperty(\"Disabled\") Disabled,\n}"
+    );
+    assert_eq!(
+        java::export::<JavaExternal>(&conf).unwrap(),
+        "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.WRAPPER_OBJECT)\n@JsonSubTypes({\n    @JsonSubTypes.Type(value = JavaExternal.Circle.class, name = \"Circle\"),\n    @JsonSubTypes.Type(value = JavaExternal.Rect.class, name = \"Rect\"),\n})\npublic sealed interface JavaExternal {\n    record Circle(\n        @JsonValue Float value\n    ) implements JavaExternal {\n        @JsonCreator(mode = JsonCreator.Mode.DELEGATING)\n        public Circle {\n        }\n    }\n\n    record Rect(\n        @JsonProperty(\"w\") Integer w,\n        @JsonProperty(\"h\") Integer h\n    ) implements JavaExternal {\n    }\n}"
+    );
+    assert_eq!(
+        java::export::<JavaInternal>(&conf).unwrap(),
+        "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = \"type\")\n@JsonSubTypes({\n    @JsonSubTypes.Type(value = JavaInternal.Click.class, name = \"Click\"),\n    @JsonSubTypes.Type(value = JavaInternal.Close.class, name = \"Close\"),\n})\npublic sealed interface JavaInternal {\n    record Click(\n        @JsonProperty(\"x\") Integer x,\n        @JsonProperty(\"y\") Integer y\n    ) implements JavaInternal {\n    }\n\n    record Close() implements JavaInternal {\n    }\n}"
+    );
+    assert_eq!(
+        java::export::<JavaAdjacent>(&conf).unwrap(),
+        "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = \"t\")\n@JsonSubTypes({\n    @JsonSubTypes.Type(value = JavaAdjacent.Text.class, name = \"Text\"),\n    @JsonSubTypes.Type(value = JavaAdjacent.Move.class, name = \"Move\"),\n    @JsonSubTypes.Type(value = JavaAdjacent.Empty.class, name = \"Empty\"),\n})\npublic sealed interface JavaAdjacent {\n    record Text(\n        @JsonProperty(\"c\") String c\n    ) implements JavaAdjacent {\n    }\n\n    record Move(\n        @JsonProperty(\"c\") Body c\n    ) implements JavaAdjacent {\n        public record Body(\n            @JsonProperty(\"x\") Integer x\n        ) {\n        }\n    }\n\n    record Empty() implements JavaAdjacent {\n    }\n}"
+    );
+
+    match java::export::<JavaMixed>(&conf) {
+        Err(JavaExportError::WithCtx { err, .. }) => {
+            assert!(matches!(*err, JavaExportError::Unsupported(_)))
+        }
+        v => panic!("unexpected result {v:?}"),
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn java_deprecated() {
+    assert_eq!(
+        java::export::<JavaLegacyUser>(&ExportConfiguration::default()).unwrap(),
+        "/**\n * @deprecated Use JavaUser\n */\n@Deprecated(since = \"1.0.0\")\npublic record JavaLegacyUser(\n    @Deprecated @JsonProperty(\"id\") Integer id\n) {\n}"
+    );
+}
+
+#[derive(Serialize, Type)]
+#[specta(export = false, rename = "JavaRenamedEvent")]
+#[serde(rename = "renamed_event", tag = "kind")]
+struct JavaTaggedEvent {
+    /// When the event happened
+    /// in milliseconds
+    at: i32,
+}
+
+#[test]
+fn java_tagged_struct() {
+    assert_eq!(
+        serde_json::to_string(&JavaTaggedEvent { at: 1 }).unwrap(),
+        r#"{"kind":"renamed_event","at":1}"#
+    );
+    assert_eq!(
+        java::export::<JavaTaggedEvent>(&ExportConfiguration::default()).unwrap(),
+        "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = \"kind\")\n@JsonTypeName(\"renamed_event\")\npublic record JavaRenamedEvent(\n    /**\n     *  When the event happened\n     *  in milliseconds\n     */\n    @JsonProperty(\"at\") Integer at\n) {\n}"
+    );
+    assert_eq!(
+        specta::ts::inline::<JavaTaggedEvent>(&Default::default()).unwrap(),
+        "{ at: number; kind: \"renamed_event\" }"
+    );
+}
@@ -0,0 +1,117 @@
+use specta::{
+    jsdoc,
+    ts::{CommentContext, CommentKind, ExportConfiguration},
+    Type,
+};
+
+macro_rules! assert_jsdoc_export {
+    ($t:ty, $e:expr) => {
+        assert_eq!(jsdoc::export::<$t>(&Default::default()).unwrap(), $e)
+    };
+    ($t:ty, $e:expr; $cfg:expr) => {
+        assert_eq!(jsdoc::export::<$t>($cfg).unwrap(), $e)
+    };
+}
+
+/// Some user of the app
+#[derive(Type)]
+#[specta(export = false)]
+struct JsDocUser {
+    /// The name shown
+    /// to other users
+    name: String,
+    age: Option<i32>,
+    #[specta(optional)]
+    nickname: Option<String>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(tag = "type")]
+struct JsDocTagged {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false, rename = "JsDocSpectaRenamedTagged")]
+#[serde(tag = "type", rename = "renamed_tagged")]
+struct JsDocRenamedTagged {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct JsDocGeneric<T> {
+    value: T,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct JsDocFlattened {
+    #[serde(flatten)]
+    inner: JsDocGeneric<String>,
+    b: bool,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct JsDocTuple(String, i32);
+
+#[derive(Type)]
+#[specta(export = false)]
+enum JsDocStatus {
+    Active,
+    Disabled { reason: String },
+}
+
+#[test]
+fn jsdoc_typedefs() {
+    assert_jsdoc_export!(
+        JsDocUser,
+        "/**\n *  Some user of the app\n * @typedef {Object} JsDocUser\n * @property {string} name - The name shown to other users\n * @property {number | null} age\n * @property {string | null} [nickname]\n */"
+    );
+    assert_jsdoc_export!(
+        JsDocUser,
+        "/**\n * @typedef {Object} JsDocUser\n * @property {string} name\n * @property {number | null} age\n * @property {string | null} [nickname]\n */";
+        &ExportConfiguration::new().comment_style(None)
+    );
+    assert_jsdoc_export!(
+        JsDocTagged,
+        "/**\n * @typedef {Object} JsDocTagged\n * @property {number} a\n * @property {\"JsDocTagged\"} type\n */"
+    );
+    assert_jsdoc_export!(
+        JsDocRenamedTagged,
+        "/**\n * @typedef {Object} JsDocSpectaRenamedTagged\n * @property {number} a\n * @property {\"renamed_tagged\"} type\n */"
+    );
+    assert_jsdoc_export!(
+        JsDocGeneric<()>,
+        "/**\n * @template T\n * @typedef {Object} JsDocGeneric\n * @property {T} value\n */"
+    );
+    assert_jsdoc_export!(
+        JsDocFlattened,
+        "/**\n * @typedef {({ value: string }) & { b: boolean }} JsDocFlattened\n */"
+    );
+    assert_jsdoc_export!(
+        JsDocTuple,
+        "/**\n * @typedef {[string, number]} JsDocTuple\n */"
+    );
+    assert_jsdoc_export!(
+        JsDocStatus,
+        "/**\n * @typedef {\"Active\" | { Disabled: { reason: string } }} JsDocStatus\n */"
+    );
+}
+
+#[test]
+fn jsdoc_comment_formatter() {
+    let conf =
+        ExportConfiguration::new().comment_formatter(|ctx: &CommentContext| match ctx.kind {
+            CommentKind::Type => format!("// The {} type\n", ctx.name),
+            _ => specta::ts::comments::js_doc(ctx).to_uppercase(),
+        });
+
+    assert_jsdoc_export!(
+        JsDocUser,
+        "/**\n *  The JsDocUser type\n * @typedef {Object} JsDocUser\n * @property {string} name - THE NAME SHOWN TO OTHER USERS\n * @property {number | null} age\n * @property {string | null} [nickname]\n */";
+        &conf
+    );
+}
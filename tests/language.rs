@@ -0,0 +1,58 @@
+use specta::{ts::Typescript, DataType, DataTypeExt, Language, Type, TypeDefs};
+
+#[derive(Type)]
+#[specta(export = false)]
+struct LanguageDemo {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct LanguageOther(String);
+
+/// A language implemented outside of Specta
+struct Names;
+
+impl Language for Names {
+    type Config = &'static str;
+    type Error = std::io::Error;
+
+    fn export_datatype(prefix: &Self::Config, def: &DataTypeExt) -> Result<String, Self::Error> {
+        match def.inner {
+            DataType::Object(_) | DataType::Tuple(_) => Ok(format!("{prefix}{}", def.name)),
+            _ => Err(std::io::Error::other("unsupported")),
+        }
+    }
+}
+
+fn definition<T: Type>() -> DataTypeExt {
+    T::definition(specta::DefOpts {
+        parent_inline: true,
+        type_map: &mut TypeDefs::default(),
+    })
+}
+
+#[test]
+fn language_trait() {
+    assert_eq!(
+        Typescript::export_type::<LanguageDemo>(&Default::default()).unwrap(),
+        "export type LanguageDemo = { a: number }"
+    );
+    assert_eq!(
+        Names::export_type::<LanguageDemo>(&"My").unwrap(),
+        "MyLanguageDemo"
+    );
+
+    let mut types = TypeDefs::default();
+    types.insert("LanguageDemo", definition::<LanguageDemo>());
+    types.insert("LanguageOther", definition::<LanguageOther>());
+
+    assert_eq!(
+        Names::export_all(&"", &types).unwrap(),
+        "LanguageDemo\n\nLanguageOther"
+    );
+    assert_eq!(
+        Typescript::export_all(&Default::default(), &types).unwrap(),
+        "export type LanguageDemo = { a: number }\n\nexport type LanguageOther = string"
+    );
+}
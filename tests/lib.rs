@@ -1,14 +1,32 @@
 #![allow(unused_variables, dead_code)]
 
 mod bigints;
+#[cfg(feature = "c")]
+mod c;
+mod comments;
+mod constraints;
 mod datatype;
+mod dependencies;
 mod duplicate_ty_name;
+#[cfg(feature = "elm")]
+mod elm;
 mod export;
+mod formatter;
+#[cfg(feature = "java")]
+mod java;
+#[cfg(feature = "jsdoc")]
+mod jsdoc;
+mod language;
 mod macro_decls;
+#[cfg(feature = "php")]
+mod php;
 mod reserved_keywords;
 pub mod ts;
+mod ts_pretty;
 mod ts_rs;
 mod ty_override;
+mod type_collection;
+mod well_known;
 
 #[test]
 fn test_compile_errors() {
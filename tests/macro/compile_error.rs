@@ -91,4 +91,26 @@ struct InvalidAttrs4 {
     a: String,
 }
 
+// `#[specta(ffi)]` types must match their Rust memory layout
+#[derive(Type)]
+#[specta(ffi)]
+struct FfiWithoutRepr {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(ffi)]
+#[repr(C)]
+struct FfiSkippedField {
+    #[serde(skip)]
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(ffi)]
+#[repr(C, u8)]
+enum FfiPrimitiveRepr {
+    A(i32),
+}
+
 // TODO: https://docs.rs/trybuild/latest/trybuild/#what-to-test
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use specta::{
+    php::{self, BigIntExportBehavior, ExportConfiguration, PhpExportError},
+    Type,
+};
+
+/// A user of the system
+#[derive(Type)]
+#[specta(export = false)]
+struct PhpUser {
+    id: i32,
+    /// The display name
+    name: String,
+    tags: Vec<String>,
+    scores: HashMap<String, f64>,
+    #[specta(optional)]
+    nickname: Option<String>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct PhpPage<T> {
+    items: Vec<T>,
+    next: Option<i32>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct PhpUserId(u64);
+
+#[derive(Type)]
+#[specta(export = false)]
+enum PhpStatus {
+    /// The user can log in
+    Active,
+    Disabled,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+enum PhpShape {
+    /// A circle with a radius
+    Circle(f32),
+    Rect {
+        w: u16,
+        h: u16,
+    },
+    Empty,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct PhpHuge {
+    a: i128,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[deprecated(since = "1.0.0", note = "Use PhpUser")]
+struct PhpLegacyUser {
+    #[deprecated]
+    id: i32,
+}
+
+#[test]
+fn php_export() {
+    let conf = ExportConfiguration::default();
+    assert_eq!(
+        php::export::<PhpUser>(&conf).unwrap(),
+        "/**\n *  A user of the system\n */\nfinal readonly class PhpUser\n{\n    /**\n     * @param list<string> $tags\n     * @param array<string, float> $scores\n     * @param string|null $nickname\n     */\n    public function __construct(\n        public int $id,\n        /**\n         *  The display name\n         */\n        public string $name,\n        public array $tags,\n        public array $scores,\n        public ?string $nickname = null,\n    ) {\n    }\n}"
+    );
+    assert_eq!(
+        php::export::<PhpPage<()>>(&conf).unwrap(),
+        "/**\n * @template T\n */\nfinal readonly class PhpPage\n{\n    /**\n     * @param list<T> $items\n     * @param int|null $next\n     */\n    public function __construct(\n        public array $items,\n        public ?int $next,\n    ) {\n    }\n}"
+    );
+    assert_eq!(
+        php::export::<PhpStatus>(&conf).unwrap(),
+        "enum PhpStatus: string\n{\n    /**\n     *  The user can log in\n     */\n    case Active = 'Active';\n    case Disabled = 'Disabled';\n}"
+    );
+    assert_eq!(
+        php::export::<PhpShape>(&conf).unwrap(),
+        "interface PhpShape\n{\n}\n\n/**\n *  A circle with a radius\n */\nfinal readonly class PhpShapeCircle implements PhpShape\n{\n    public function __construct(\n        public float $value,\n    ) {\n    }\n}\n\nfinal readonly class PhpShapeRect implements PhpShape\n{\n    public function __construct(\n        public int $w,\n        public int $h,\n    ) {\n    }\n}\n\nfinal readonly class PhpShapeEmpty implements PhpShape\n{\n}"
+    );
+}
+
+#[test]
+fn php_bigint() {
+    // PHP's `int` is signed so it can't hold every `u64`
+    assert!(matches!(
+        php::inline::<u64>(&ExportConfiguration::default()),
+        Err(PhpExportError::BigIntForbidden)
+    ));
+    assert_eq!(
+        php::export::<PhpUserId>(&ExportConfiguration::new().bigint(BigIntExportBehavior::String))
+            .unwrap(),
+        "final readonly class PhpUserId\n{\n    public function __construct(\n        public string $value,\n    ) {\n    }\n}"
+    );
+    assert_eq!(
+        php::export::<PhpUserId>(&ExportConfiguration::new().bigint(BigIntExportBehavior::Int))
+            .unwrap(),
+        "final readonly class PhpUserId\n{\n    public function __construct(\n        public int $value,\n    ) {\n    }\n}"
+    );
+
+    match php::export::<PhpHuge>(&ExportConfiguration::new().bigint(BigIntExportBehavior::Int)) {
+        Err(PhpExportError::WithCtx { err, .. }) => match *err {
+            PhpExportError::WithCtx { err, .. } => {
+                assert!(matches!(*err, PhpExportError::Unsupported(_)))
+            }
+            err => panic!("unexpected error {err:?}"),
+        },
+        v => panic!("unexpected result {v:?}"),
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn php_deprecated() {
+    let conf = ExportConfiguration::default();
+    assert_eq!(
+        php::export::<PhpLegacyUser>(&conf).unwrap(),
+        "/**\n * @deprecated 1.0.0 Use PhpUser\n */\nfinal readonly class PhpLegacyUser\n{\n    public function __construct(\n        /**\n         * @deprecated\n         */\n        public int $id,\n    ) {\n    }\n}"
+    );
+}
@@ -2,12 +2,16 @@
 
 use std::{
     cell::RefCell,
+    collections::{BTreeSet, HashSet},
     net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
     path::PathBuf,
 };
 
 use serde::Serialize;
-use specta::{ts::ExportConfiguration, Type};
+use specta::{
+    ts::{ExportConfiguration, PrettyConfig},
+    DataType, DefOpts, PrimitiveType, Type,
+};
 
 macro_rules! assert_ts {
     ($t:ty, $e:expr) => {
@@ -128,10 +132,141 @@ fn typescript_types() {
         "/**\n *  Type level doc comment\n */\nexport type DocComments = { a: string }"
     );
     assert_ts_export!(DocComments, "export type DocComments = { a: string }"; &ExportConfiguration::new().comment_style(None));
+}
+
+#[test]
+fn typescript_deprecated() {
+    assert_ts_export!(
+        DeprecatedType,
+        "/**\n * @deprecated\n */\nexport type DeprecatedType = { a: number }"
+    );
+    assert_ts_export!(
+        DeprecatedTypeWithMsg,
+        "/**\n * @deprecated Look at you big man using a deprecation message\n */\nexport type DeprecatedTypeWithMsg = { a: number }"
+    );
+    assert_ts_export!(
+        DeprecatedTypeWithMsg2,
+        "/**\n * @deprecated Look at you big man using a deprecation message\n */\nexport type DeprecatedTypeWithMsg2 = { a: number }"
+    );
+    assert_ts_export!(
+        DeprecatedTypeWithSince,
+        "/**\n *  Some docs\n * @deprecated Use something else (since 1.0.0)\n */\nexport type DeprecatedTypeWithSince = { a: number }"
+    );
+    assert_ts_export!(
+        DeprecatedTypeWithSpecta,
+        "/**\n * @deprecated Use something else\n */\nexport type DeprecatedTypeWithSpecta = { a: number }"
+    );
+    assert_ts_export!(
+        DeprecatedFields,
+        "export type DeprecatedFields = {\n  a: number;\n  /**\n   * @deprecated\n   */\n  b: string;\n  /**\n   * @deprecated This field is cringe!\n   */\n  c: string;\n  /**\n   * @deprecated This field is cringe!\n   */\n  d: string;\n}";
+        &ExportConfiguration::new().pretty(Some(PrettyConfig::default()))
+    );
+    assert_ts_export!(
+        DeprecatedVariants,
+        "export type DeprecatedVariants =\n  | \"A\"\n  /**\n   * @deprecated Use A (since 0.2.0)\n   */\n  | \"B\"";
+        &ExportConfiguration::new().pretty(Some(PrettyConfig::default()))
+    );
+    assert_ts_export!(
+        DeprecatedFields,
+        "export type DeprecatedFields = { a: number; b: string; c: string; d: string }";
+        &ExportConfiguration::new().comment_style(None)
+    );
+}
+
+#[test]
+fn typescript_brand() {
+    assert_ts_export!(
+        BrandedUserId,
+        "export type BrandedUserId = string & { readonly __brand: \"BrandedUserId\" }"
+    );
+    assert_ts_export!(
+        BrandedNullable,
+        "export type BrandedNullable = (number | null) & { readonly __brand: \"BrandedNullable\" }"
+    );
+    assert_ts_export!(UnbrandedOrderId, "export type UnbrandedOrderId = string");
+
+    // Only newtypes can be branded
+    assert_ts_export!(
+        UnbrandedPair,
+        "export type UnbrandedPair = [string, number]";
+        &ExportConfiguration::new().brand_newtypes(true)
+    );
+
+    let conf = ExportConfiguration::new()
+        .brand_newtypes(true)
+        .brand_name(Some(|name| format!("app.{name}")));
+    assert_ts_export!(
+        UnbrandedOrderId,
+        "export type UnbrandedOrderId = string & { readonly __brand: \"app.UnbrandedOrderId\" }";
+        &conf
+    );
+    assert_ts!(BrandedOrder, "{ user: BrandedUserId }");
+}
+
+#[test]
+fn typescript_readonly() {
+    assert_ts_export!(
+        ReadonlyCart,
+        "export type ReadonlyCart = { readonly items: readonly string[]; readonly notes: readonly (string | null)[]; readonly prices: { readonly [key: string]: number }; readonly size: readonly [number, number] }"
+    );
+    assert_ts!(
+        ReadonlyCart,
+        "{ readonly items: readonly string[]; readonly notes: readonly (string | null)[]; readonly prices: { readonly [key: string]: number }; readonly size: readonly [number, number] }"
+    );
+
+    let conf = ExportConfiguration::new().readonly(true);
+    assert_ts_export!(
+        ReadonlyEvent,
+        "export type ReadonlyEvent = { readonly kind: \"Created\"; readonly tags: readonly string[] } | { readonly kind: \"Deleted\" }";
+        &conf
+    );
+    // Nested arrays are wrapped so the modifier applies to the inner array
+    assert_ts_export!(
+        ReadonlyMatrix,
+        "export type ReadonlyMatrix = { readonly rows: readonly (readonly string[])[]; readonly pairs: readonly (readonly [number, number])[] }"
+    );
+    // Overridden items are wrapped based on the type they're replaced with
+    let override_conf = ExportConfiguration::new().override_type::<ReadonlyLabel>(DataType::List(
+        Box::new(DataType::Primitive(PrimitiveType::String)),
+    ));
+    assert_ts_export!(
+        ReadonlyLabels,
+        "export type ReadonlyLabels = { readonly labels: readonly (readonly string[])[] }";
+        &override_conf
+    );
+    // References to other types keep their own modifiers
+    assert_ts_export!(
+        MutableOrder,
+        "export type MutableOrder = { cart: ReadonlyCart; ids: number[] }"
+    );
+    assert_ts_export!(
+        MutableOrder,
+        "export type MutableOrder = { readonly cart: ReadonlyCart; readonly ids: readonly number[] }";
+        &conf
+    );
+}
+
+#[test]
+fn typescript_sets() {
+    let typ = <BTreeSet<String>>::inline(
+        DefOpts {
+            parent_inline: true,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    );
+    assert_eq!(
+        typ,
+        DataType::Set(Box::new(DataType::Primitive(PrimitiveType::String)))
+    );
 
-    // assert_ts_export!(DeprecatedType, "");
-    // assert_ts_export!(DeprecatedTypeWithMsg, "");
-    // assert_ts_export!(DeprecatedFields, "");
+    // Sets are serialized as arrays
+    assert_ts!(HashSet<String>, "string[]");
+    assert_ts!(TaggedCart, "{ tags: string[]; items: number[] }");
+    assert_eq!(
+        specta::ts::inline::<TaggedCart>(&ExportConfiguration::new().readonly(true)).unwrap(),
+        "{ readonly tags: readonly string[]; readonly items: readonly number[] }"
+    );
 }
 
 #[derive(Type)]
@@ -294,35 +429,128 @@ pub struct DocComments {
     a: String,
 }
 
-// #[derive(Type)]
-// #[specta(export = false)]
-// #[deprecated]
-// struct DeprecatedType {
-//     a: i32,
-// }
-
-// #[derive(Type)]
-// #[specta(export = false)]
-// #[deprecated = "Look at you big man using a deprecation message"]
-// struct DeprecatedTypeWithMsg {
-//     a: i32,
-// }
-
-// #[derive(Type)]
-// #[specta(export = false)]
-// #[deprecated(note = "Look at you big man using a deprecation message")]
-// struct DeprecatedTypeWithMsg2 {
-//     a: i32,
-// }
-
-// #[derive(Type)]
-// #[specta(export = false)]
-// struct DeprecatedFields {
-//     a: i32,
-//     // #[deprecated]
-//     b: String,
-//     #[deprecated = "This field is cringe!"]
-//     c: String,
-//     #[deprecated(note = "This field is cringe!")]
-//     d: String,
-// }
+#[derive(Type)]
+#[specta(export = false)]
+#[deprecated]
+struct DeprecatedType {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[deprecated = "Look at you big man using a deprecation message"]
+struct DeprecatedTypeWithMsg {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[deprecated(note = "Look at you big man using a deprecation message")]
+struct DeprecatedTypeWithMsg2 {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct DeprecatedFields {
+    a: i32,
+    #[deprecated]
+    b: String,
+    #[deprecated = "This field is cringe!"]
+    c: String,
+    #[deprecated(note = "This field is cringe!")]
+    d: String,
+}
+
+/// Some docs
+#[derive(Type)]
+#[specta(export = false)]
+#[deprecated(since = "1.0.0", note = "Use something else")]
+struct DeprecatedTypeWithSince {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false, deprecated = "Use something else")]
+struct DeprecatedTypeWithSpecta {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+enum DeprecatedVariants {
+    A,
+    #[deprecated(since = "0.2.0", note = "Use A")]
+    B,
+}
+
+#[derive(Type)]
+#[specta(export = false, brand)]
+struct BrandedUserId(String);
+
+#[derive(Type)]
+#[specta(export = false, brand)]
+struct BrandedNullable(Option<i32>);
+
+#[derive(Type)]
+#[specta(export = false)]
+struct UnbrandedOrderId(String);
+
+#[derive(Type)]
+#[specta(export = false)]
+struct UnbrandedPair(String, i32);
+
+#[derive(Type)]
+#[specta(export = false)]
+struct BrandedOrder {
+    user: BrandedUserId,
+}
+
+#[derive(Type)]
+#[specta(export = false, readonly)]
+struct ReadonlyCart {
+    items: Vec<String>,
+    notes: Vec<Option<String>>,
+    prices: std::collections::BTreeMap<String, i32>,
+    size: (i32, i32),
+}
+
+#[derive(Type)]
+#[specta(export = false, readonly)]
+struct ReadonlyMatrix {
+    rows: Vec<Vec<String>>,
+    pairs: Vec<(i32, i32)>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct ReadonlyLabel {
+    text: String,
+}
+
+#[derive(Type)]
+#[specta(export = false, readonly)]
+struct ReadonlyLabels {
+    labels: Vec<ReadonlyLabel>,
+}
+
+#[derive(Type)]
+#[specta(export = false, tag = "kind")]
+enum ReadonlyEvent {
+    Created { tags: Vec<String> },
+    Deleted,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct MutableOrder {
+    cart: ReadonlyCart,
+    ids: Vec<i32>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct TaggedCart {
+    tags: BTreeSet<String>,
+    items: Vec<i32>,
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use specta::{
+    ts::{ExportConfiguration, PrettyConfig},
+    Type,
+};
+
+use crate::ts::assert_ts_export;
+
+#[derive(Type)]
+#[specta(export = false)]
+struct PrettyUser {
+    id: i32,
+    name: Option<String>,
+    tags: Vec<String>,
+    scores: HashMap<String, i32>,
+    pair: (String, bool),
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+enum PrettyEvent {
+    Created { id: i32, name: String },
+    Deleted(i32),
+    RenamedEverythingInTheSystem,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+enum PrettyShort {
+    A,
+    B,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct PrettyNested {
+    inner: PrettyNestedInner,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[specta(inline)]
+struct PrettyNestedInner {
+    a_long_field_name: String,
+    another_long_field_name: String,
+    yet_another_long_field: String,
+}
+
+#[test]
+fn pretty_typescript() {
+    let conf = ExportConfiguration::default()
+        .comment_style(None)
+        .pretty(Some(PrettyConfig::default()));
+
+    assert_ts_export!(
+        PrettyUser,
+        "export type PrettyUser = {\n  id: number;\n  name: string | null;\n  tags: string[];\n  scores: { [key: string]: number };\n  pair: [string, boolean];\n}";
+        &conf
+    );
+    assert_ts_export!(
+        PrettyEvent,
+        "export type PrettyEvent =\n  | { Created: { id: number; name: string } }\n  | { Deleted: number }\n  | \"RenamedEverythingInTheSystem\"";
+        &conf
+    );
+    assert_ts_export!(PrettyShort, "export type PrettyShort = \"A\" | \"B\""; &conf);
+    assert_ts_export!(
+        PrettyNested,
+        "export type PrettyNested = {\n    inner: {\n        a_long_field_name: string;\n        another_long_field_name: string;\n        yet_another_long_field: string;\n    };\n}";
+        &ExportConfiguration::default().pretty(Some(PrettyConfig {
+            indent: "    ".into(),
+            line_width: 60,
+        }))
+    );
+}
@@ -10,6 +10,13 @@ struct TaggedType {
     b: i32,
 }
 
+#[derive(Type)]
+#[specta(export = false, rename = "SpectaRenamedTaggedType")]
+#[serde(tag = "type", rename = "renamed_tagged_type")]
+struct RenamedTaggedType {
+    a: i32,
+}
+
 #[test]
 #[cfg(feature = "serde")]
 fn test() {
@@ -17,6 +24,11 @@ fn test() {
         TaggedType,
         r#"{ a: number; b: number; type: "TaggedType" }"#
     );
+    // The tag holds the name serde gives the struct, which `#[specta(rename)]` doesn't affect
+    assert_ts!(
+        RenamedTaggedType,
+        r#"{ a: number; type: "renamed_tagged_type" }"#
+    );
 }
 
 // TODO: Make it sure this test is run in CI. Won't run without `--all-features`.
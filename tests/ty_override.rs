@@ -1,6 +1,9 @@
-use specta::Type;
+use specta::{
+    ts::{self, ExportConfiguration},
+    DataType, PrimitiveType, Type,
+};
 
-use crate::ts::assert_ts;
+use crate::ts::{assert_ts, assert_ts_export};
 
 // This test is to do with how the Macro passes the tokens
 #[derive(Type)]
@@ -30,3 +33,74 @@ fn type_override() {
     );
     assert_ts!(InvalidToValidType, "{ cause: any | null }");
 }
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct ForeignUser {
+    id: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct OverriddenFields {
+    user: ForeignUser,
+    data: serde_json::Value,
+    extra: Option<serde_json::Value>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub enum OverriddenVariants {
+    // Has the same name as the overridden type but isn't replaced
+    ForeignUser { id: i32 },
+    User(ForeignUser),
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct OverriddenPage<T> {
+    items: Vec<T>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct OverriddenPages {
+    users: OverriddenPage<ForeignUser>,
+    ids: OverriddenPage<i32>,
+}
+
+#[test]
+fn export_time_override() {
+    let conf = ExportConfiguration::new()
+        .override_type::<ForeignUser>("import(\"./api\").User")
+        .override_type::<serde_json::Value>("JsonValue");
+    assert_eq!(
+        ts::inline::<OverriddenFields>(&conf).unwrap(),
+        "{ user: import(\"./api\").User; data: JsonValue; extra: JsonValue | null }"
+    );
+    assert_eq!(
+        ts::inline::<OverriddenVariants>(&conf).unwrap(),
+        "{ ForeignUser: { id: number } } | { User: import(\"./api\").User }"
+    );
+    assert_ts_export!(
+        ForeignUser,
+        "export type ForeignUser = import(\"./api\").User";
+        &conf
+    );
+
+    // References to generic types are matched by their generic arguments too
+    let conf = ExportConfiguration::new()
+        .override_type::<OverriddenPage<ForeignUser>>("import(\"./api\").UserPage");
+    assert_eq!(
+        ts::inline::<OverriddenPages>(&conf).unwrap(),
+        "{ users: import(\"./api\").UserPage; ids: OverriddenPage<number> }"
+    );
+
+    // Overrides can be a datatype and can target types by name
+    let conf = ExportConfiguration::new()
+        .override_type_named("ForeignUser", DataType::Primitive(PrimitiveType::String));
+    assert_eq!(
+        ts::inline::<OverriddenFields>(&conf).unwrap(),
+        "{ user: string; data: any; extra: any | null }"
+    );
+}
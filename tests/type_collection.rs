@@ -0,0 +1,94 @@
+use specta::{
+    export::Export,
+    ts::{ExportConfiguration, Typescript},
+    Language, Type, TypeCollection,
+};
+
+#[derive(Type)]
+#[specta(export = false)]
+struct CollectionUser {
+    name: String,
+    role: CollectionRole,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+enum CollectionRole {
+    Admin,
+    Member,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct CollectionOther(i32);
+
+#[test]
+fn test_type_collection() {
+    let mut types = TypeCollection::new();
+    types.register::<CollectionUser>().register::<String>();
+    assert_eq!(
+        types.iter().map(|t| t.name).collect::<Vec<_>>(),
+        vec!["CollectionRole", "CollectionUser"]
+    );
+
+    let mut other = TypeCollection::new();
+    other.register::<CollectionOther>();
+    types.merge(other);
+    assert_eq!(types.len(), 3);
+
+    assert_eq!(
+        types.remove("CollectionRole").map(|t| t.name),
+        Some("CollectionRole")
+    );
+    assert!(types.get("CollectionRole").is_none());
+
+    assert_eq!(
+        Typescript::export_all(&ExportConfiguration::default(), types.as_type_defs()).unwrap(),
+        "export type CollectionOther = number\n\nexport type CollectionUser = { name: string; role: CollectionRole }"
+    );
+}
+
+#[test]
+fn test_export_type_collection() {
+    let mut types = TypeCollection::new();
+    types.register::<CollectionUser>();
+
+    // `export = false` only applies to the global type store
+    Export::new()
+        .types(types)
+        .language::<Typescript>(ExportConfiguration::default(), "./bindings7.ts")
+        .write()
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string("./bindings7.ts").unwrap(),
+        "// This file has been generated by Specta. DO NOT EDIT.\n\nexport type CollectionRole = \"Admin\" | \"Member\"\n\nexport type CollectionUser = { name: string; role: CollectionRole }"
+    );
+    std::fs::remove_file("./bindings7.ts").unwrap();
+}
+
+#[test]
+fn test_export_output() {
+    use specta::export::Output;
+
+    let mut types = TypeCollection::new();
+    types.register::<CollectionOther>();
+
+    Export::new()
+        .types(types)
+        .language::<Typescript>(
+            ExportConfiguration::default(),
+            Output::new("./bindings8.ts")
+                .header("/* eslint-disable */")
+                .declaration("export type Helper = string")
+                .footer("// end"),
+        )
+        .write()
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string("./bindings8.ts").unwrap(),
+        "/* eslint-disable */\n\nexport type Helper = string\n\nexport type CollectionOther = number\n\n// end"
+    );
+    std::fs::remove_file("./bindings8.ts").unwrap();
+}
@@ -0,0 +1,135 @@
+use specta::{
+    ts::{self, ExportConfiguration, PrettyConfig, Typescript},
+    DataType, DefOpts, ImplLocation, Language, Type, TypeCollection, TypeSid, WellKnownType,
+};
+
+use crate::ts::{assert_ts, assert_ts_export};
+
+// Stands in for a type from a crate like `chrono` so the tests don't depend on optional features
+struct Timestamp;
+
+impl Type for Timestamp {
+    const NAME: &'static str = "Timestamp";
+    const SID: TypeSid = specta::sid!();
+    const IMPL_LOCATION: ImplLocation = specta::impl_location!();
+
+    fn inline(_: DefOpts, _: &[DataType]) -> DataType {
+        DataType::WellKnown(WellKnownType::DateTime)
+    }
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct Event {
+    at: Timestamp,
+    ended_at: Option<Timestamp>,
+}
+
+#[test]
+fn typescript_well_known_types() {
+    assert_ts!(Timestamp, "string");
+    assert_ts!(Event, "{ at: string; ended_at: string | null }");
+
+    let conf = ExportConfiguration::new()
+        .well_known_type(
+            WellKnownType::DateTime,
+            "`${number}-${number}-${number}T${string}`",
+        )
+        .pretty(Some(PrettyConfig::default()));
+    assert_eq!(
+        ts::datatype(&conf, &DataType::WellKnown(WellKnownType::DateTime)).unwrap(),
+        "`${number}-${number}-${number}T${string}`"
+    );
+    assert_eq!(
+        ts::datatype(&conf, &DataType::WellKnown(WellKnownType::Uuid)).unwrap(),
+        "string"
+    );
+    // Template literals aren't broken across lines by the pretty printer
+    assert_ts_export!(
+        Event,
+        "export type Event = {\n  at: `${number}-${number}-${number}T${string}`;\n  ended_at: `${number}-${number}-${number}T${string}` | null;\n}";
+        &conf
+    );
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct JsonPayload {
+    data: serde_json::Value,
+    extra: Option<serde_json::Value>,
+}
+
+#[test]
+fn typescript_json_value() {
+    assert_ts!(JsonPayload, "{ data: any; extra: any | null }");
+    assert_eq!(
+        ts::inline::<JsonPayload>(&ExportConfiguration::new().any_as_unknown(true)).unwrap(),
+        "{ data: unknown; extra: unknown | null }"
+    );
+
+    let conf = ExportConfiguration::new().json_value(true);
+    assert_eq!(
+        ts::inline::<JsonPayload>(&conf).unwrap(),
+        "{ data: JsonValue; extra: JsonValue | null }"
+    );
+
+    let mut types = TypeCollection::new();
+    types.register::<JsonPayload>();
+    assert_eq!(
+        Typescript::export_all(&conf, types.as_type_defs()).unwrap(),
+        format!(
+            "{}\n\nexport type JsonPayload = {{ data: JsonValue; extra: JsonValue | null }}",
+            ts::JSON_VALUE_DECLARATION
+        )
+    );
+}
+
+#[cfg(feature = "java")]
+#[test]
+fn java_well_known_types() {
+    use specta::java;
+
+    let conf = java::ExportConfiguration::new();
+    for (ty, expected) in [
+        (WellKnownType::DateTime, "OffsetDateTime"),
+        (WellKnownType::NaiveDateTime, "LocalDateTime"),
+        (WellKnownType::Date, "LocalDate"),
+        (WellKnownType::Time, "LocalTime"),
+        (WellKnownType::Uuid, "UUID"),
+        (WellKnownType::Decimal, "BigDecimal"),
+    ] {
+        assert_eq!(
+            java::datatype(&conf, &DataType::WellKnown(ty)).unwrap(),
+            expected
+        );
+    }
+
+    let conf = conf.well_known_type(WellKnownType::DateTime, "String");
+    assert_eq!(
+        java::datatype(&conf, &DataType::WellKnown(WellKnownType::DateTime)).unwrap(),
+        "String"
+    );
+}
+
+#[test]
+fn well_known_types_fall_back_to_their_representation() {
+    #[cfg(feature = "elm")]
+    assert_eq!(
+        specta::elm::datatype(
+            &Default::default(),
+            &DataType::WellKnown(WellKnownType::Uuid)
+        )
+        .unwrap(),
+        "String"
+    );
+    #[cfg(feature = "php")]
+    assert_eq!(
+        specta::php::datatype(
+            &Default::default(),
+            &DataType::WellKnown(WellKnownType::Decimal)
+        )
+        .unwrap()
+        .native,
+        "string"
+    );
+}